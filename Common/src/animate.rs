@@ -0,0 +1,158 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+// Days with something to watch (a grid filling in, a search spreading out) hand pictures of
+// each step to frame(). Nothing is drawn or even built unless a player has been installed,
+// which `aoc animate` does, so solving normally costs one relaxed load per step.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+type Player = Box<dyn FnMut(Frame) + Send>;
+static PLAYER: Mutex<Option<Player>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    // Handy for telling apart any number of things, such as Day 9's basins
+    pub const CYCLE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 91,
+            Color::Green => 92,
+            Color::Yellow => 93,
+            Color::Blue => 94,
+            Color::Magenta => 95,
+            Color::Cyan => 96,
+            Color::White => 97,
+            Color::Gray => 90,
+        }
+    }
+}
+
+// One picture of a day's state: a caption above rows of characters, each with an optional
+// colour
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub rows: Vec<Vec<(char, Option<Color>)>>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, rows: Vec<Vec<(char, Option<Color>)>>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            rows,
+        }
+    }
+
+    // Colours an existing text picture, such as Grid::render or Day 20's render_image, a
+    // character at a time
+    pub fn from_text(
+        caption: impl Into<String>,
+        text: &str,
+        mut color: impl FnMut(char) -> Option<Color>,
+    ) -> Frame {
+        let rows = text
+            .lines()
+            .map(|line| line.chars().map(|c| (c, color(c))).collect())
+            .collect();
+        Frame::new(caption, rows)
+    }
+
+    // The caption and picture without colours, as written by --frames
+    pub fn plain(&self) -> String {
+        let mut text = self.caption.clone() + "\n";
+        for row in &self.rows {
+            text.extend(row.iter().map(|&(c, _)| c));
+            text.push('\n');
+        }
+        text
+    }
+
+    // The caption and picture with ANSI colours, cropped to `width` by `height` characters
+    // so big maps don't scroll the terminal
+    pub fn ansi(&self, width: usize, height: usize) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "{}\x1b[K", self.caption);
+        for row in self.rows.iter().take(height.saturating_sub(1)) {
+            let mut current = None;
+            for &(c, color) in row.iter().take(width) {
+                if color != current {
+                    match color {
+                        Some(color) => {
+                            let _ = write!(text, "\x1b[{}m", color.ansi_code());
+                        }
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                text.push(c);
+            }
+            text.push_str("\x1b[0m\x1b[K\n");
+        }
+        text
+    }
+}
+
+// Installs the function every frame is passed to, or removes it with None
+pub fn set_player(player: Option<Player>) {
+    ENABLED.store(player.is_some(), Ordering::Relaxed);
+    *PLAYER.lock().unwrap() = player;
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Passes a frame to the player, if there is one. `build` is only called when there is.
+pub fn frame(build: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+    let frame = build();
+    if let Some(player) = PLAYER.lock().unwrap().as_mut() {
+        player(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_plain_and_coloured() {
+        let frame = Frame::from_text("step 1", "#.\n.#", |c| (c == '#').then_some(Color::Red));
+        assert_eq!(frame.plain(), "step 1\n#.\n.#\n");
+        assert_eq!(frame.ansi(1, 2), "step 1\x1b[K\n\x1b[91m#\x1b[0m\x1b[K\n");
+    }
+
+    #[test]
+    fn frames_reach_the_player() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        set_player(Some(Box::new(move |frame: Frame| {
+            sender.send(frame.caption).unwrap();
+        })));
+        frame(|| Frame::new("first", Vec::new()));
+        set_player(None);
+        frame(|| panic!("Built without a player"));
+
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec!["first"]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Answer;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::Path;
+use toml::{Table, Value};
+
+// Inputs are identified by the SHA-256 of their contents, so a registry can hold answers for
+// several people's inputs and an edited input never matches a stale answer
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+// Known answers, stored as TOML keyed by day, then input hash, then part:
+//
+// [day1.3f2a...]
+// part1 = "1162"
+// part2 = "1190"
+#[derive(Debug, Clone, Default)]
+pub struct AnswerRegistry {
+    table: Table,
+}
+
+impl AnswerRegistry {
+    // A missing file is an empty registry
+    pub fn load(path: &Path) -> Result<AnswerRegistry, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(AnswerRegistry::default()),
+            Err(e) => return Err(format!("Unable to read {}: {}", path.display(), e)),
+        };
+
+        let table = text
+            .parse::<Table>()
+            .map_err(|e| format!("Invalid answers file {}: {}", path.display(), e))?;
+        Ok(AnswerRegistry { table })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.table.to_string())
+    }
+
+    pub fn get(&self, day: usize, hash: &str, part: u32) -> Option<&str> {
+        self.table
+            .get(&format!("day{}", day))?
+            .get(hash)?
+            .get(format!("part{}", part))?
+            .as_str()
+    }
+
+    pub fn check(&self, day: usize, hash: &str, part: u32, answer: &Answer) -> Verdict {
+        match self.get(day, hash, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, day: usize, hash: &str, part: u32, answer: &Answer) {
+        let day_table = Self::subtable(&mut self.table, &format!("day{}", day));
+        let input_table = Self::subtable(day_table, hash);
+        input_table.insert(format!("part{}", part), Value::String(answer.to_string()));
+    }
+
+    fn subtable<'a>(table: &'a mut Table, key: &str) -> &'a mut Table {
+        let value = table
+            .entry(key)
+            .or_insert_with(|| Value::Table(Table::new()));
+        if !value.is_table() {
+            *value = Value::Table(Table::new());
+        }
+        value.as_table_mut().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_contents() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn records_and_checks_answers() {
+        let mut registry = AnswerRegistry::default();
+        let hash = input_hash("199\n200\n");
+        registry.record(1, &hash, 1, &Answer::Int(1));
+
+        let reloaded = AnswerRegistry {
+            table: registry.table.to_string().parse().unwrap(),
+        };
+        assert_eq!(reloaded.check(1, &hash, 1, &Answer::Int(1)), Verdict::Pass);
+        assert_eq!(
+            reloaded.check(1, &hash, 1, &Answer::Int(2)),
+            Verdict::Fail {
+                expected: "1".to_string()
+            }
+        );
+        assert_eq!(
+            reloaded.check(1, &hash, 2, &Answer::Int(1)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            reloaded.check(2, &hash, 1, &Answer::Int(1)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn grid_answers_survive_a_round_trip() {
+        let mut registry = AnswerRegistry::default();
+        let grid = Answer::Grid("#.#\n.#.".to_string());
+        registry.record(13, "abc", 2, &grid);
+
+        let reloaded = AnswerRegistry {
+            table: registry.table.to_string().parse().unwrap(),
+        };
+        assert_eq!(reloaded.check(13, "abc", 2, &grid), Verdict::Pass);
+    }
+}
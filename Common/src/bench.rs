@@ -0,0 +1,281 @@
+use crate::solution::{solve_part, Solution};
+use crate::ParseError;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use toml::{Table, Value};
+
+// Fast phases are repeated within a sample until it takes about this long, so timer
+// resolution doesn't swamp the measurement
+const TARGET_SAMPLE_TIME: Duration = Duration::from_millis(5);
+
+// Changes smaller than this are treated as noise even when the spread is tiny
+const NOISE_THRESHOLD: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub samples: usize,
+    pub iterations: u32,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>, iterations: u32) -> Stats {
+        samples.sort();
+        let count = samples.len();
+        let secs: Vec<f64> = samples.iter().map(|s| s.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / count as f64;
+        let variance = if count > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (count - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+
+        Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            samples: count,
+            iterations,
+        }
+    }
+}
+
+// Times `f` over `samples` samples and reports the time of a single call
+pub fn measure<F: FnMut()>(samples: usize, mut f: F) -> Stats {
+    // The first call doubles as warm-up and as an estimate of how many calls fit in a sample
+    let estimate_start = Instant::now();
+    f();
+    let estimate = estimate_start.elapsed().max(Duration::from_nanos(1));
+    let iterations = (TARGET_SAMPLE_TIME.as_nanos() / estimate.as_nanos()).clamp(1, 1_000_000);
+    let iterations = iterations as u32;
+
+    let samples = (0..samples.max(1))
+        .map(|_| {
+            let sample_start = Instant::now();
+            for _ in 0..iterations {
+                f();
+            }
+            sample_start.elapsed() / iterations
+        })
+        .collect();
+
+    Stats::from_samples(samples, iterations)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    // Named "parse", "part1" and "part2"
+    pub phases: Vec<(String, Stats)>,
+}
+
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[u32],
+    config: &S::Config,
+    samples: usize,
+) -> Result<BenchReport, ParseError> {
+    let parsed = S::parse(input, config).map_err(|e| e.locate(input))?;
+
+    let mut phases = vec![(
+        "parse".to_string(),
+        measure(samples, || {
+            black_box(S::parse(black_box(input), config).ok());
+        }),
+    )];
+    for &part in parts {
+        let stats = measure(samples, || {
+            black_box(solve_part::<S>(black_box(&parsed), part, config));
+        });
+        phases.push((format!("part{}", part), stats));
+    }
+
+    Ok(BenchReport { phases })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Improved,
+    Regressed,
+    NoChange,
+}
+
+// Compares a new measurement against a baseline's. A change counts when it is bigger than
+// both the noise threshold and twice the combined spread of the two measurements.
+pub fn compare(baseline: &Stats, current: &Stats) -> (f64, Change) {
+    let old = baseline.mean.as_secs_f64();
+    let new = current.mean.as_secs_f64();
+    if old == 0.0 {
+        return (0.0, Change::NoChange);
+    }
+
+    let ratio = (new - old) / old;
+    let spread = 2.0 * (baseline.stddev.as_secs_f64() + current.stddev.as_secs_f64()) / old;
+    let change = if ratio.abs() <= NOISE_THRESHOLD.max(spread) {
+        Change::NoChange
+    } else if ratio < 0.0 {
+        Change::Improved
+    } else {
+        Change::Regressed
+    };
+    (ratio, change)
+}
+
+// Saved benchmark results, one table per day with one entry per phase and the hash of the
+// input they were measured on
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    table: Table,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Unable to read baseline {}: {}", path.display(), e))?;
+        let table = text
+            .parse::<Table>()
+            .map_err(|e| format!("Invalid baseline {}: {}", path.display(), e))?;
+        Ok(Baseline { table })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.table.to_string())
+    }
+
+    pub fn get(&self, day: usize, phase: &str) -> Option<Stats> {
+        let entry = self
+            .table
+            .get(&format!("day{}", day))?
+            .get(phase)?
+            .as_table()?;
+        let nanos = |key: &str| {
+            let value = entry.get(key)?.as_integer()?;
+            Some(Duration::from_nanos(value.max(0) as u64))
+        };
+        let count = |key: &str| entry.get(key)?.as_integer();
+
+        Some(Stats {
+            mean: nanos("mean_ns")?,
+            median: nanos("median_ns")?,
+            stddev: nanos("stddev_ns")?,
+            samples: count("samples")? as usize,
+            iterations: count("iterations")? as u32,
+        })
+    }
+
+    pub fn input_hash(&self, day: usize) -> Option<&str> {
+        self.table
+            .get(&format!("day{}", day))?
+            .get("input_sha256")?
+            .as_str()
+    }
+
+    // Times measured on a different input are dropped, as they can't be compared with the new
+    // ones
+    pub fn set_input_hash(&mut self, day: usize, hash: &str) {
+        if self.input_hash(day).is_some_and(|old| old != hash) {
+            self.day_table(day).clear();
+        }
+        self.day_table(day)
+            .insert("input_sha256".to_string(), Value::String(hash.to_string()));
+    }
+
+    pub fn record(&mut self, day: usize, phase: &str, stats: &Stats) {
+        let mut entry = Table::new();
+        let nanos = |d: Duration| Value::Integer(d.as_nanos() as i64);
+        entry.insert("mean_ns".to_string(), nanos(stats.mean));
+        entry.insert("median_ns".to_string(), nanos(stats.median));
+        entry.insert("stddev_ns".to_string(), nanos(stats.stddev));
+        entry.insert("samples".to_string(), Value::Integer(stats.samples as i64));
+        entry.insert(
+            "iterations".to_string(),
+            Value::Integer(stats.iterations as i64),
+        );
+
+        self.day_table(day)
+            .insert(phase.to_string(), Value::Table(entry));
+    }
+
+    fn day_table(&mut self, day: usize) -> &mut Table {
+        let value = self
+            .table
+            .entry(format!("day{}", day))
+            .or_insert_with(|| Value::Table(Table::new()));
+        if !value.is_table() {
+            *value = Value::Table(Table::new());
+        }
+        value.as_table_mut().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = Stats::from_samples(ms(&[4, 1, 3, 2]), 1);
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        // Sample standard deviation of 1, 2, 3, 4
+        assert_eq!(stats.stddev.as_micros(), 1290);
+    }
+
+    #[test]
+    fn flags_changes_beyond_the_noise() {
+        let baseline = Stats::from_samples(ms(&[10, 10, 10]), 1);
+        let faster = Stats::from_samples(ms(&[5, 5, 5]), 1);
+        let same = Stats::from_samples(ms(&[10, 10, 11]), 1);
+
+        assert_eq!(compare(&baseline, &faster).1, Change::Improved);
+        assert_eq!(compare(&faster, &baseline).1, Change::Regressed);
+        assert_eq!(compare(&baseline, &same).1, Change::NoChange);
+    }
+
+    #[test]
+    fn baseline_round_trips() {
+        let stats = Stats::from_samples(ms(&[1, 2, 3]), 7);
+        let mut baseline = Baseline::default();
+        baseline.record(14, "part2", &stats);
+        baseline.set_input_hash(14, "ab12");
+
+        let reloaded = Baseline {
+            table: baseline.table.to_string().parse().unwrap(),
+        };
+        assert_eq!(reloaded.get(14, "part2"), Some(stats));
+        assert_eq!(reloaded.get(14, "part1"), None);
+        assert_eq!(reloaded.input_hash(14), Some("ab12"));
+    }
+
+    #[test]
+    fn new_input_drops_old_times() {
+        let stats = Stats::from_samples(ms(&[1, 2, 3]), 7);
+        let mut baseline = Baseline::default();
+        baseline.set_input_hash(14, "ab12");
+        baseline.record(14, "part1", &stats);
+        baseline.set_input_hash(6, "cd34");
+        baseline.record(6, "part1", &stats);
+
+        baseline.set_input_hash(14, "ab12");
+        assert_eq!(baseline.get(14, "part1"), Some(stats));
+        baseline.set_input_hash(14, "ef56");
+        assert_eq!(baseline.get(14, "part1"), None);
+        assert_eq!(baseline.get(6, "part1"), Some(stats));
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// The puzzle constants a day can be run with, so variants can be explored from the command
+// line with --set key=value instead of by editing the solver. Days declare theirs with
+// config!(), and days without any use ().
+pub trait Config: Default {
+    // Every setting as (name, value), in declaration order
+    fn settings(&self) -> Vec<(&'static str, String)>;
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    // Rejects combinations the solver can't run with, checked once everything is set
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl Config for () {
+    fn settings(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(unknown_setting(key, &[]))
+    }
+}
+
+pub fn unknown_setting(key: &str, settings: &[(&str, String)]) -> String {
+    if settings.is_empty() {
+        return format!("Unknown setting '{}', this day has no settings", key);
+    }
+
+    let known: Vec<String> = settings
+        .iter()
+        .map(|(name, value)| format!("{} ({})", name, value))
+        .collect();
+    format!(
+        "Unknown setting '{}', expected one of: {}",
+        key,
+        known.join(", ")
+    )
+}
+
+pub fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| format!("Invalid value '{}' for {}: {}", value, key, e))
+}
+
+// Declares a config struct along with its defaults:
+//
+// aoc_common::config! {
+//     pub struct Day6Config {
+//         part1_days: usize = 80,
+//         part2_days: usize = 256,
+//     }
+// }
+//
+// Fields are public and named on the command line as they are here. A `check` function
+// can follow the struct to reject values the day can't handle.
+#[macro_export]
+macro_rules! config {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+        $(check = $check:path;)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default),*
+                }
+            }
+        }
+
+        impl $crate::config::Config for $name {
+            fn settings(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), self.$field.to_string())),*]
+            }
+
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(stringify!($field) => self.$field = $crate::config::parse_value(key, value)?,)*
+                    _ => return Err($crate::config::unknown_setting(key, &self.settings())),
+                }
+                Ok(())
+            }
+
+            $(fn check(&self) -> Result<(), String> {
+                $check(self)
+            })?
+        }
+    };
+}
+
+// The --set key=value pairs given on the command line, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    values: Vec<(String, String)>,
+}
+
+impl Settings {
+    pub fn push_arg(&mut self, arg: &str) -> Result<(), String> {
+        match arg.split_once('=') {
+            Some((key, value)) if !key.is_empty() => {
+                self.values.push((key.to_string(), value.to_string()));
+                Ok(())
+            }
+            _ => Err(format!("Invalid setting '{}', expected key=value", arg)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // The day's defaults with these settings applied on top
+    pub fn apply<C: Config>(&self) -> Result<C, String> {
+        let mut config = C::default();
+        for (key, value) in &self.values {
+            config.set(key, value)?;
+        }
+        config.check()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::config! {
+        struct TestConfig {
+            steps: usize = 10,
+            target: i64 = -3,
+        }
+        check = check_steps;
+    }
+
+    fn check_steps(config: &TestConfig) -> Result<(), String> {
+        match config.steps {
+            0 => Err("steps must be at least 1".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    fn settings(args: &[&str]) -> Settings {
+        let mut settings = Settings::default();
+        for arg in args {
+            settings.push_arg(arg).unwrap();
+        }
+        settings
+    }
+
+    #[test]
+    fn applies_settings_over_defaults() {
+        let config: TestConfig = settings(&["target=7"]).apply().unwrap();
+        assert_eq!(
+            config,
+            TestConfig {
+                steps: 10,
+                target: 7
+            }
+        );
+    }
+
+    #[test]
+    fn reports_bad_settings() {
+        assert_eq!(
+            settings(&["speed=2"]).apply::<TestConfig>().unwrap_err(),
+            "Unknown setting 'speed', expected one of: steps (10), target (-3)"
+        );
+        assert!(settings(&["steps=x"]).apply::<TestConfig>().is_err());
+        assert!(settings(&["steps=0"]).apply::<TestConfig>().is_err());
+        assert!(settings(&["steps=1"]).apply::<()>().is_err());
+        assert!(Settings::default().push_arg("steps").is_err());
+    }
+}
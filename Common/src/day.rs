@@ -0,0 +1,143 @@
+use crate::config::Settings;
+use crate::input::{self, Source};
+use crate::output::{self, Format, CSV_HEADER};
+use crate::parallel;
+use crate::solution::{self, Report, Solution};
+use crate::store::InputStore;
+use crate::verbosity::Verbosity;
+use crate::{input_hash, Answer};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::{self, Duration};
+
+pub fn print_answer(part: u32, answer: &Answer) {
+    if answer.is_multiline() {
+        println!("Part {} Answer:\n{}", part, answer);
+    } else {
+        println!("Part {} Answer: {}", part, answer);
+    }
+}
+
+fn print_text(report: &Report, read_time: Duration, verbosity: Verbosity) {
+    if verbosity.is_quiet() {
+        for result in &report.parts {
+            println!("{}", result.answer);
+        }
+        return;
+    }
+
+    for result in &report.parts {
+        print_answer(result.part, &result.answer);
+
+        println!();
+        println!("----------------------------------");
+        println!();
+    }
+
+    println!("Read time: {:?}", read_time);
+    println!("Parse time: {:?}", report.parse_time);
+    for result in &report.parts {
+        println!("Part {} time: {:?}", result.part, result.time);
+    }
+}
+
+struct DayArgs {
+    source: Source,
+    format: Format,
+    settings: Settings,
+    parallel: bool,
+    verbosity: Verbosity,
+}
+
+fn parse_args(day: usize, args: &[String]) -> Result<DayArgs, String> {
+    let usage = format!(
+        "Usage: day_{} [--input <path|->] [--example <n>] [--user <name>] \
+         [--format <text|json|csv>] [--set <key=value>]... [--parallel] [-q|-v|-vv]",
+        day
+    );
+    let mut source = None;
+    let mut user = None;
+    let mut format = Format::Text;
+    let mut settings = Settings::default();
+    let mut parallel = false;
+    let mut verbosity = Verbosity::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--parallel" {
+            parallel = true;
+            continue;
+        }
+        if verbosity.apply_flag(arg) {
+            continue;
+        }
+
+        let value = args.next().ok_or_else(|| usage.clone())?;
+        match arg.as_str() {
+            "--input" | "-i" => source = Some(Source::from_input_arg(value)),
+            "--example" | "-e" => source = Some(Source::from_example_arg(value)?),
+            "--user" | "-u" => {
+                InputStore::check_user(value)?;
+                user = Some(value.as_str());
+            }
+            "--format" | "-f" => format = value.parse()?,
+            "--set" | "-s" => settings.push_arg(value)?,
+            _ => return Err(usage),
+        }
+    }
+
+    let source = match (source, user) {
+        (Some(_), Some(_)) => return Err("--user can't be used with --input or --example".into()),
+        (Some(source), None) => source,
+        (None, user) => Source::default_for(user, day, PathBuf::from("input.txt")),
+    };
+    Ok(DayArgs {
+        source,
+        format,
+        settings,
+        parallel,
+        verbosity,
+    })
+}
+
+fn exit_with(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+// Shared main() for the per-day binaries, which are run from inside the day's directory and
+// read the input store, or input.txt when the store doesn't have the day
+pub fn run_day<S: Solution>(day: usize) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(day, &args).unwrap_or_else(|e| exit_with(e));
+    let config = args
+        .settings
+        .apply::<S::Config>()
+        .unwrap_or_else(|e| exit_with(e));
+    parallel::set_enabled(args.parallel);
+    args.verbosity.init();
+
+    let read_time_start = time::Instant::now();
+    let (name, input) = input::read(&args.source, S::EXAMPLES).unwrap_or_else(|e| exit_with(e));
+    let read_time = read_time_start.elapsed();
+
+    let report = solution::run::<S>(&input, &[1, 2], &config)
+        .unwrap_or_else(|e| exit_with(e.with_file(name)));
+
+    let records = output::records(day, &input_hash(&input), read_time, &report);
+    match args.format {
+        Format::Text => print_text(&report, read_time, args.verbosity),
+        Format::Json => {
+            for record in &records {
+                println!("{}", record.to_json());
+            }
+        }
+        Format::Csv => {
+            println!("{}", CSV_HEADER);
+            for record in &records {
+                println!("{}", record.to_csv());
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+// Where in the input a parse error happened. Lines and columns start at 1 like a compiler's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    // Number of characters to underline, at least 1
+    pub width: usize,
+    pub line_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    // Boxed to keep the Err side of parser results small
+    pub location: Option<Box<Location>>,
+    pub expected: String,
+    pub found: String,
+    // Address and length of the offending slice. Parsers only ever see pieces of the input,
+    // so the error remembers where its slice lives and locate() turns that into a
+    // line/column once the whole input is at hand.
+    span: (usize, usize),
+}
+
+impl ParseError {
+    // `span` must be a slice of the puzzle input (what lines(), split() and trim() return)
+    // for the error to be given a location
+    pub fn new(span: &str, expected: impl Into<String>) -> ParseError {
+        let found = if span.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("'{}'", span.lines().next().unwrap_or_default())
+        };
+
+        ParseError {
+            file: None,
+            location: None,
+            expected: expected.into(),
+            found,
+            span: (span.as_ptr() as usize, span.len()),
+        }
+    }
+
+    // For when something is missing: points just past the end of `text`
+    pub fn after(text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(&text[text.len()..], expected)
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+
+    // Fills in the line and column by finding the error's span inside `input`. Errors that
+    // were already located, or whose span came from somewhere else, are left alone.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let (address, len) = self.span;
+        if self.location.is_some() || address < start || address + len > start + input.len() {
+            return self;
+        }
+
+        let offset = address - start;
+        if offset == input.trim_end().len() && len == 0 {
+            self.found = "end of input".to_string();
+        }
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line_text = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+        let rest_of_line = line_text.get(offset - line_start..).unwrap_or_default();
+        let width = input[offset..offset + len]
+            .chars()
+            .count()
+            .min(rest_of_line.chars().count())
+            .max(1);
+
+        self.location = Some(Box::new(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column,
+            width,
+            line_text: line_text.to_string(),
+        }));
+        self
+    }
+}
+
+// Printed like a compiler diagnostic:
+//
+// error: expected a number, found 'x'
+//  --> input.txt:3:5
+//   |
+// 3 | 0,9 x 5,9
+//   |     ^
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: expected {}, found {}", self.expected, self.found)?;
+
+        let file = match &self.file {
+            Some(file) => file.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        match &self.location {
+            Some(location) => {
+                let gutter = " ".repeat(location.line.to_string().len());
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{}--> {}:{}:{}",
+                    gutter, file, location.line, location.column
+                )?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", location.line, location.line_text)?;
+                write!(
+                    f,
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(location.column - 1),
+                    "^".repeat(location.width)
+                )
+            }
+            None if self.file.is_some() => write!(f, "\n --> {}", file),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_span_in_input() {
+        let input = "0,9 -> 5,9\n8,0 -> x,8\n";
+        let span = &input[18..19];
+        let error = ParseError::new(span, "a number").locate(input);
+
+        let location = error.location.as_deref().unwrap();
+        assert_eq!((location.line, location.column, location.width), (2, 8, 1));
+        assert_eq!(location.line_text, "8,0 -> x,8");
+        assert_eq!(error.found, "'x'");
+    }
+
+    #[test]
+    fn missing_token_at_end_of_input() {
+        let input = "forward 5\nup\n";
+        let error = ParseError::after(&input[10..12], "an amount").locate(input);
+
+        assert_eq!(error.found, "end of input");
+        assert_eq!(error.location.unwrap().column, 3);
+    }
+
+    #[test]
+    fn displays_like_a_compiler() {
+        let input = "forward 5\nsideways 3";
+        let error = ParseError::new(&input[10..18], "a direction")
+            .locate(input)
+            .with_file("course.txt");
+
+        let expected = "error: expected a direction, found 'sideways'\n \
+                        --> course.txt:2:1\n  \
+                        |\n\
+                        2 | sideways 3\n  \
+                        | ^^^^^^^^";
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn unrelated_span_is_not_located() {
+        let other = String::from("12");
+        let error = ParseError::new(&other, "a letter").locate("abc");
+        assert!(error.location.is_none());
+    }
+}
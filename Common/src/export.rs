@@ -0,0 +1,320 @@
+use crate::animate::{Color, Frame};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// Frames from the animations saved as image files with `aoc run <day> --export <path>`. The
+// format comes from the extension: a PPM or PNG of the last frame, or a GIF of all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Result<ImageFormat, String> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        match extension.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            "gif" => Ok(ImageFormat::Gif),
+            _ => Err(format!(
+                "Unknown image format for {}, expected .ppm, .png or .gif",
+                path.display()
+            )),
+        }
+    }
+}
+
+const BACKGROUND: [u8; 3] = [16, 16, 16];
+// Characters without a colour, such as walls
+const FOREGROUND: [u8; 3] = [200, 200, 200];
+// Digits are drawn at one of ten brightnesses, so heights and risk levels show, and anything
+// else at full brightness
+const LEVELS: usize = 11;
+
+fn rgb(color: Color) -> [u8; 3] {
+    match color {
+        Color::Red => [230, 60, 60],
+        Color::Green => [80, 200, 80],
+        Color::Yellow => [230, 200, 60],
+        Color::Blue => [70, 110, 230],
+        Color::Magenta => [200, 80, 200],
+        Color::Cyan => [70, 200, 220],
+        Color::White => [255, 255, 255],
+        Color::Gray => [110, 110, 110],
+    }
+}
+
+const COLORS: [Color; 8] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::Gray,
+];
+
+// Every colour a picture can use: the background, then each base colour at every brightness.
+// It's small enough to be the palette of a PNG or GIF.
+fn palette() -> Vec<[u8; 3]> {
+    let bases = std::iter::once(FOREGROUND).chain(COLORS.iter().map(|&color| rgb(color)));
+    let mut palette = vec![BACKGROUND];
+    for base in bases {
+        for level in 0..LEVELS {
+            let brightness = match level {
+                10 => 1.0,
+                digit => 0.4 + 0.6 * digit as f64 / 9.0,
+            };
+            palette.push(base.map(|channel| (channel as f64 * brightness).round() as u8));
+        }
+    }
+    palette
+}
+
+fn palette_index(c: char, color: Option<Color>) -> u8 {
+    let base = match color {
+        None if c == '.' || c == ' ' => return 0,
+        None => 0,
+        Some(color) => 1 + COLORS.iter().position(|&other| other == color).unwrap(),
+    };
+    let level = c.to_digit(10).unwrap_or(10) as usize;
+    (1 + base * LEVELS + level) as u8
+}
+
+// A frame as one palette index per cell, padded with background to its widest row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u8>,
+}
+
+impl Picture {
+    pub fn from_frame(frame: &Frame) -> Picture {
+        let width = frame.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let height = frame.rows.len();
+        let mut cells = vec![0; width * height];
+        for (y, row) in frame.rows.iter().enumerate() {
+            for (x, &(c, color)) in row.iter().enumerate() {
+                cells[y * width + x] = palette_index(c, color);
+            }
+        }
+        Picture {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // The cells blown up to `scale` pixels square, on a background of `width` by `height`
+    // pixels
+    fn pixels(&self, scale: usize, width: usize, height: usize) -> Vec<u8> {
+        let mut pixels = vec![0; width * height];
+        for y in 0..(self.height * scale).min(height) {
+            for x in 0..(self.width * scale).min(width) {
+                pixels[y * width + x] = self.cells[(y / scale) * self.width + x / scale];
+            }
+        }
+        pixels
+    }
+}
+
+// Cells are drawn as squares of pixels, big enough for small pictures like Day 13's code to
+// be readable and down to one pixel for big ones like Day 15's full map
+fn scale_for(width: usize, height: usize) -> usize {
+    (512 / width.max(height).max(1)).clamp(1, 16)
+}
+
+fn write_ppm(picture: &Picture, out: &mut impl Write) -> io::Result<()> {
+    let scale = scale_for(picture.width, picture.height);
+    let (width, height) = (picture.width * scale, picture.height * scale);
+    let palette = palette();
+    write!(out, "P6\n{} {}\n255\n", width, height)?;
+    for index in picture.pixels(scale, width, height) {
+        out.write_all(&palette[index as usize])?;
+    }
+    Ok(())
+}
+
+fn write_png(picture: &Picture, out: impl Write) -> Result<(), String> {
+    let scale = scale_for(picture.width, picture.height);
+    let (width, height) = (picture.width * scale, picture.height * scale);
+    let too_big = || format!("{}x{} is too big for a PNG", width, height);
+    let mut encoder = png::Encoder::new(
+        out,
+        u32::try_from(width).map_err(|_| too_big())?,
+        u32::try_from(height).map_err(|_| too_big())?,
+    );
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette().concat());
+
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer
+        .write_image_data(&picture.pixels(scale, width, height))
+        .map_err(|e| e.to_string())
+}
+
+// Every picture in turn, each on a background as big as the largest, as pictures such as Day
+// 13's paper change size
+fn write_gif(pictures: &[&Picture], frame_delay: u16, out: impl Write) -> Result<(), String> {
+    let most_cells = |size: fn(&Picture) -> usize| pictures.iter().map(|&p| size(p)).max();
+    let (cells_wide, cells_high) = (most_cells(|p| p.width), most_cells(|p| p.height));
+    let (cells_wide, cells_high) = (cells_wide.unwrap_or(0), cells_high.unwrap_or(0));
+    let scale = scale_for(cells_wide, cells_high);
+    let (width, height) = (cells_wide * scale, cells_high * scale);
+    let too_big = || format!("{}x{} is too big for a GIF", width, height);
+    let gif_width = u16::try_from(width).map_err(|_| too_big())?;
+    let gif_height = u16::try_from(height).map_err(|_| too_big())?;
+
+    let palette = palette().concat();
+    let mut encoder =
+        gif::Encoder::new(out, gif_width, gif_height, &palette).map_err(|e| e.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+    for (i, picture) in pictures.iter().enumerate() {
+        let pixels = picture.pixels(scale, width, height);
+        let mut frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None);
+        // The final state is held for a couple of seconds before it loops
+        frame.delay = if i + 1 == pictures.len() {
+            200
+        } else {
+            frame_delay
+        };
+        encoder.write_frame(&frame).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// The most cells a GIF's frames are kept in memory as, which Day 5's thousand frames of a
+// million cells would otherwise go well past
+const GIF_BUDGET: usize = 64 << 20;
+
+// Collects frames as a day draws them. Still images only need the last one. A GIF gets every
+// frame until they go over budget, then every other one is dropped and half as many kept
+// from then on, so long runs are evenly sampled.
+#[derive(Debug)]
+pub struct Recorder {
+    format: ImageFormat,
+    pictures: Vec<Picture>,
+    keep_every: usize,
+    seen: usize,
+    last: Option<Picture>,
+    budget: usize,
+}
+
+impl Recorder {
+    pub fn new(format: ImageFormat) -> Recorder {
+        Recorder {
+            format,
+            pictures: Vec::new(),
+            keep_every: 1,
+            seen: 0,
+            last: None,
+            budget: GIF_BUDGET,
+        }
+    }
+
+    pub fn record(&mut self, frame: &Frame) {
+        let picture = Picture::from_frame(frame);
+        if self.format == ImageFormat::Gif && self.seen.is_multiple_of(self.keep_every) {
+            self.pictures.push(picture.clone());
+            let cells: usize = self.pictures.iter().map(|p| p.cells.len()).sum();
+            if cells > self.budget && self.pictures.len() > 1 {
+                let mut i = 0;
+                self.pictures.retain(|_| {
+                    i += 1;
+                    i % 2 == 1
+                });
+                self.keep_every *= 2;
+            }
+        }
+        self.seen += 1;
+        self.last = Some(picture);
+    }
+
+    // How many frames went into the image
+    pub fn frames(&self) -> usize {
+        match self.format {
+            ImageFormat::Gif => self.pictures.len() + self.ends_unkept() as usize,
+            _ => self.last.is_some() as usize,
+        }
+    }
+
+    // Whether the last frame was dropped by the sampling, which a GIF still ends on
+    fn ends_unkept(&self) -> bool {
+        self.seen > 0 && !(self.seen - 1).is_multiple_of(self.keep_every)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let last = match &self.last {
+            Some(last) => last,
+            None => return Err("Nothing was drawn to export".to_string()),
+        };
+
+        let file = File::create(path)
+            .map_err(|e| format!("Unable to create {}: {}", path.display(), e))?;
+        let mut out = BufWriter::new(file);
+        let written = match self.format {
+            ImageFormat::Ppm => write_ppm(last, &mut out).map_err(|e| e.to_string()),
+            ImageFormat::Png => write_png(last, &mut out),
+            ImageFormat::Gif => {
+                let mut pictures: Vec<&Picture> = self.pictures.iter().collect();
+                if self.ends_unkept() {
+                    pictures.push(last);
+                }
+                write_gif(&pictures, 10, &mut out)
+            }
+        };
+        written
+            .and_then(|_| out.flush().map_err(|e| e.to_string()))
+            .map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_ppm() {
+        let frame = Frame::from_text("", "#.\n9", |c| (c != '.').then_some(Color::Red));
+        let picture = Picture::from_frame(&frame);
+        assert_eq!(picture.width, 2);
+        assert_eq!(picture.cells[1], 0);
+        assert_eq!(picture.cells[3], 0);
+
+        let mut ppm = Vec::new();
+        write_ppm(&picture, &mut ppm).unwrap();
+        let header = "P6\n32 32\n255\n";
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + 32 * 32 * 3);
+        // The top left cell is '#' in full red and the bottom right is background
+        assert_eq!(ppm[header.len()..header.len() + 3], [230, 60, 60]);
+        assert_eq!(ppm[ppm.len() - 3..], BACKGROUND);
+    }
+
+    #[test]
+    fn samples_long_gifs() {
+        let mut recorder = Recorder::new(ImageFormat::Gif);
+        recorder.budget = 16;
+        let frame = Frame::from_text("", "12\n34", |_| None);
+        for _ in 0..9 {
+            recorder.record(&frame);
+        }
+        // Four frames fill the budget, so every other one is dropped on the 5th and again on
+        // the 9th, leaving the 1st, 5th and 9th
+        assert_eq!(recorder.keep_every, 4);
+        assert_eq!(recorder.pictures.len(), 3);
+        assert_eq!(recorder.frames(), 3);
+        // The 10th isn't kept but is still where the GIF ends
+        recorder.record(&frame);
+        assert!(recorder.ends_unkept());
+        assert_eq!(recorder.frames(), 4);
+    }
+}
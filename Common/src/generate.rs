@@ -0,0 +1,120 @@
+use crate::Answer;
+use std::ops::RangeInclusive;
+
+// A small seeded generator (SplitMix64) for synthetic inputs. It is kept in-house rather than
+// taken from a crate so a seed produces the same input on every version and platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in 0..n, which must not be empty
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        let len = (end.wrapping_sub(start) as u64).wrapping_add(1);
+        let offset = if len == 0 {
+            self.next_u64()
+        } else {
+            ((self.next_u64() as u128 * len as u128) >> 64) as u64
+        };
+        start.wrapping_add(offset as i64)
+    }
+
+    // True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// A synthetic input along with any answers that are known from how it was built
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Vec<(u32, Answer)>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Generated {
+        Generated {
+            input,
+            answers: Vec::new(),
+        }
+    }
+
+    pub fn with_answer(mut self, part: u32, answer: impl Into<Answer>) -> Generated {
+        self.answers.push((part, answer.into()));
+        self
+    }
+
+    pub fn answer(&self, part: u32) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|(answer_part, _)| *answer_part == part)
+            .map(|(_, answer)| answer)
+    }
+}
+
+// Joins generated lines into an input, with the trailing newline puzzle inputs have
+pub fn lines<I: IntoIterator<Item = String>>(lines: I) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_are_reproducible() {
+        let mut a = Rng::new(2021);
+        let mut b = Rng::new(2021);
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(2022).next_u64(), first[0]);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.range(-2..=2)));
+        }
+        assert_eq!(rng.range(5..=5), 5);
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::store::InputStore;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+// Where a day's puzzle input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    // One of the day's embedded examples, counting from 1
+    Example(usize),
+    // A user's input imported into the input store
+    Stored { user: String, day: usize },
+}
+
+impl Source {
+    // The value of --input, where - means stdin
+    pub fn from_input_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    // The value of --example
+    pub fn from_example_arg(arg: &str) -> Result<Source, String> {
+        match arg.parse::<usize>() {
+            Ok(n) if n >= 1 => Ok(Source::Example(n)),
+            _ => Err(format!("Invalid example '{}', expected 1 or more", arg)),
+        }
+    }
+
+    // Where a day's input comes from when no --input or --example is given. A user named with
+    // --user has to have the input in the store, otherwise the store is tried for the default
+    // user before falling back to the checked-in `fallback`.
+    pub fn default_for(user: Option<&str>, day: usize, fallback: PathBuf) -> Source {
+        if let Some(user) = user {
+            return Source::Stored {
+                user: user.to_string(),
+                day,
+            };
+        }
+
+        let user = InputStore::default_user();
+        match InputStore::open() {
+            Ok(store) if store.contains(&user, day) => Source::Stored { user, day },
+            _ => Source::File(fallback),
+        }
+    }
+
+    // Whether there is anything to read, so a missing input can be told apart from a bad one
+    pub fn exists(&self) -> bool {
+        match self {
+            Source::File(path) => path.is_file(),
+            Source::Stored { user, day } => {
+                InputStore::open().is_ok_and(|store| store.contains(user, *day))
+            }
+            Source::Stdin | Source::Example(_) => true,
+        }
+    }
+}
+
+// Embeds a day's example files, named relative to the day's directory, for
+// Solution::EXAMPLES. Must be used from a file directly in the day's src directory.
+#[macro_export]
+macro_rules! examples {
+    ($($file:literal),* $(,)?) => {
+        &[$(($file, include_str!(concat!("../", $file)))),*]
+    };
+}
+
+// Inputs saved on Windows have \r\n line endings, which would otherwise end up at the end of
+// every line the parsers see
+pub fn normalize(text: String) -> String {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n")
+    } else {
+        text
+    }
+}
+
+// Reads the input from `source`, returning it along with a name to report parse errors
+// against. `examples` are the day's embedded examples as (file name, contents).
+pub fn read(source: &Source, examples: &[(&str, &str)]) -> Result<(PathBuf, String), String> {
+    let (name, text) = match source {
+        Source::File(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+            (path.clone(), text)
+        }
+        Source::Stdin => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|e| format!("Unable to read stdin: {}", e))?;
+            (PathBuf::from("<stdin>"), text)
+        }
+        Source::Example(n) => match examples.get(n - 1) {
+            Some(&(file, text)) => (PathBuf::from(file), text.to_string()),
+            None if examples.is_empty() => return Err("There are no examples".to_string()),
+            None => {
+                let names: Vec<&str> = examples.iter().map(|&(file, _)| file).collect();
+                return Err(format!(
+                    "There is no example {}, only {}: {}",
+                    n,
+                    examples.len(),
+                    names.join(", ")
+                ));
+            }
+        },
+        Source::Stored { user, day } => InputStore::open()?.read(user, *day)?,
+    };
+
+    Ok((name, normalize(text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[(&str, &str)] = &[("example1.txt", "1\r\n2\r\n"), ("example2.txt", "3\n")];
+
+    #[test]
+    fn picks_examples() {
+        let (name, text) = read(&Source::Example(1), EXAMPLES).unwrap();
+        assert_eq!(name, PathBuf::from("example1.txt"));
+        assert_eq!(text, "1\n2\n");
+
+        let error = read(&Source::Example(3), EXAMPLES).unwrap_err();
+        assert_eq!(
+            error,
+            "There is no example 3, only 2: example1.txt, example2.txt"
+        );
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(Source::from_input_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_input_arg("in.txt"),
+            Source::File(PathBuf::from("in.txt"))
+        );
+        assert_eq!(Source::from_example_arg("2"), Ok(Source::Example(2)));
+        assert!(Source::from_example_arg("0").is_err());
+    }
+}
@@ -0,0 +1,27 @@
+pub mod animate;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod day;
+pub mod error;
+pub mod export;
+pub mod generate;
+pub mod input;
+pub mod output;
+pub mod parallel;
+pub mod parse;
+pub mod solution;
+pub mod store;
+pub mod verbosity;
+
+pub use answer::Answer;
+pub use answers::{input_hash, AnswerRegistry, Verdict};
+pub use config::{Config, Settings};
+pub use day::run_day;
+pub use error::{Location, ParseError};
+pub use input::Source;
+pub use output::{Format, Record};
+pub use solution::{solve, solve_with, DynSolution, Solution};
+pub use store::InputStore;
+pub use verbosity::Verbosity;
@@ -0,0 +1,141 @@
+use crate::solution::Report;
+use crate::Answer;
+use serde_json::json;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // The human-readable output the days have always printed
+    Text,
+    // One JSON object per line, so several days can be streamed into the same file
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Invalid format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,read_ns,parse_ns,solve_ns,input_sha256";
+
+// The result of one part along with the time it took to get there. The read and parse
+// times are shared by both parts of a day, and the input hash says which input it was.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: usize,
+    pub part: u32,
+    pub answer: Answer,
+    pub read_time: Duration,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub input_hash: String,
+}
+
+pub fn records(day: usize, input_hash: &str, read_time: Duration, report: &Report) -> Vec<Record> {
+    report
+        .parts
+        .iter()
+        .map(|result| Record {
+            day,
+            part: result.part,
+            answer: result.answer.clone(),
+            read_time,
+            parse_time: report.parse_time,
+            solve_time: result.time,
+            input_hash: input_hash.to_string(),
+        })
+        .collect()
+}
+
+impl Record {
+    // Answers are always strings, the same as in answers.toml, so grids and numbers too big
+    // for a double come through intact
+    pub fn to_json(&self) -> String {
+        json!({
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer.to_string(),
+            "read_ns": self.read_time.as_nanos() as u64,
+            "parse_ns": self.parse_time.as_nanos() as u64,
+            "solve_ns": self.solve_time.as_nanos() as u64,
+            "input_sha256": self.input_hash,
+        })
+        .to_string()
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            csv_field(&self.answer.to_string()),
+            self.read_time.as_nanos(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos(),
+            self.input_hash
+        )
+    }
+}
+
+// Quotes a field if it holds anything that would break the row apart
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer) -> Record {
+        Record {
+            day: 13,
+            part: 2,
+            answer,
+            read_time: Duration::from_nanos(1500),
+            parse_time: Duration::from_micros(20),
+            solve_time: Duration::from_millis(3),
+            input_hash: "ab12".to_string(),
+        }
+    }
+
+    #[test]
+    fn formats_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&record(Answer::Grid("#.\n.#".to_string())).to_json()).unwrap();
+        assert_eq!(json["day"], 13);
+        assert_eq!(json["answer"], "#.\n.#");
+        assert_eq!(json["read_ns"], 1500);
+        assert_eq!(json["parse_ns"], 20_000);
+        assert_eq!(json["solve_ns"], 3_000_000);
+        assert_eq!(json["input_sha256"], "ab12");
+    }
+
+    #[test]
+    fn formats_csv() {
+        assert_eq!(
+            record(Answer::Int(42)).to_csv(),
+            "13,2,42,1500,20000,3000000,ab12"
+        );
+        assert_eq!(
+            record(Answer::Str("a,\"b\"".to_string())).to_csv(),
+            "13,2,\"a,\"\"b\"\"\",1500,20000,3000000,ab12"
+        );
+    }
+}
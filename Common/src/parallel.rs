@@ -0,0 +1,64 @@
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Whether days may split their work across rayon's thread pool. It is off unless --parallel
+// is given, so timings stay comparable with single-threaded runs by default.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Calls `f` on every item, in parallel when enabled. Results are in the same order as `items`
+// either way.
+pub fn map<'a, T, R, F>(items: &'a [T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&'a T) -> R + Sync + Send,
+{
+    if enabled() {
+        items.par_iter().map(f).collect()
+    } else {
+        items.iter().map(f).collect()
+    }
+}
+
+// The first result in `items` order that `f` returns, in parallel when enabled, so the answer
+// doesn't depend on which thread finishes first
+pub fn find_map<'a, T, R, F>(items: &'a [T], f: F) -> Option<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&'a T) -> Option<R> + Sync + Send,
+{
+    if enabled() {
+        items.par_iter().find_map_first(f)
+    } else {
+        items.iter().find_map(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both modes are checked in one test, since the switch is shared by every test thread
+    #[test]
+    fn same_results_either_way() {
+        let items: Vec<u64> = (0..1000).collect();
+        let square = |&x: &u64| x * x;
+        let first_big = |&x: &u64| (x * x > 5000).then_some(x);
+
+        for enabled in [false, true] {
+            set_enabled(enabled);
+            assert_eq!(map(&items, square)[999], 998_001);
+            assert_eq!(find_map(&items, first_big), Some(71));
+        }
+        set_enabled(false);
+    }
+}
@@ -0,0 +1,53 @@
+// Small building blocks for the day parsers. Everything takes and returns slices of the
+// puzzle input so that errors can be located back in it.
+use crate::ParseError;
+use std::str::FromStr;
+
+// Parses a whole token as a number, ignoring surrounding whitespace
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    let token = token.trim();
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number"))
+}
+
+// The character of `text` starting at byte `index`, as a slice for error reporting
+pub fn char_at(text: &str, index: usize) -> &str {
+    let len = text[index..].chars().next().map_or(0, |c| c.len_utf8());
+    &text[index..index + len]
+}
+
+// Each character of `line` as a single decimal digit
+pub fn digits(line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(ParseError::new(char_at(line, i), "a digit")),
+        })
+        .collect()
+}
+
+// Splits `text` around the first `separator`, pointing at the end of `text` when it's missing
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::after(text, format!("'{}'", separator)))
+}
+
+// Takes the next piece of `text` from `pieces` (usually a split of `text`)
+pub fn next<'a>(
+    pieces: &mut impl Iterator<Item = &'a str>,
+    text: &'a str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    pieces
+        .next()
+        .ok_or_else(|| ParseError::after(text, expected))
+}
+
+// Fails when `pieces` has anything left over
+pub fn end<'a>(pieces: &mut impl Iterator<Item = &'a str>) -> Result<(), ParseError> {
+    match pieces.next() {
+        Some(piece) => Err(ParseError::new(piece, "end of line")),
+        None => Ok(()),
+    }
+}
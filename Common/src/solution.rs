@@ -0,0 +1,140 @@
+use crate::bench::{self, BenchReport};
+use crate::config::{Config, Settings};
+use crate::generate::{Generated, Rng};
+use crate::{Answer, ParseError};
+use std::time::{Duration, Instant};
+
+// The shape every day follows: parse the puzzle input once, then solve each part from the
+// parsed form. Both steps see the day's config, which holds the puzzle's constants.
+pub trait Solution {
+    type Input;
+    type Config: Config;
+
+    // The examples from the puzzle text as (file name, contents), usually from examples!()
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[];
+
+    fn parse(input: &str, config: &Self::Config) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Answer;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Answer;
+
+    // A random input in the puzzle's format, for stress testing. `size` scales it (lines,
+    // grid side and so on, depending on the day) and defaults to about the puzzle's size.
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated;
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u32,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+// Solution has associated input and config types, so it can't be used as a trait object.
+// This wrapper erases them so the runner can keep every day in a single table. Settings
+// must have passed check_settings before they are given to run or bench.
+pub trait DynSolution {
+    fn run(&self, input: &str, parts: &[u32], settings: &Settings) -> Result<Report, ParseError>;
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[u32],
+        settings: &Settings,
+        samples: usize,
+    ) -> Result<BenchReport, ParseError>;
+    fn examples(&self) -> &'static [(&'static str, &'static str)];
+    fn check_settings(&self, settings: &Settings) -> Result<(), String>;
+    // The day's settings as (name, default value)
+    fn default_settings(&self) -> Vec<(&'static str, String)>;
+    fn generate(&self, seed: u64, size: Option<usize>) -> Generated;
+}
+
+fn config<S: Solution>(settings: &Settings) -> S::Config {
+    settings
+        .apply()
+        .unwrap_or_else(|e| panic!("Settings were not checked: {}", e))
+}
+
+impl<S: Solution> DynSolution for S {
+    fn run(&self, input: &str, parts: &[u32], settings: &Settings) -> Result<Report, ParseError> {
+        run::<S>(input, parts, &config::<S>(settings))
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[u32],
+        settings: &Settings,
+        samples: usize,
+    ) -> Result<BenchReport, ParseError> {
+        bench::bench::<S>(input, parts, &config::<S>(settings), samples)
+    }
+
+    fn examples(&self) -> &'static [(&'static str, &'static str)] {
+        S::EXAMPLES
+    }
+
+    fn check_settings(&self, settings: &Settings) -> Result<(), String> {
+        settings.apply::<S::Config>().map(|_| ())
+    }
+
+    fn default_settings(&self) -> Vec<(&'static str, String)> {
+        S::Config::default().settings()
+    }
+
+    fn generate(&self, seed: u64, size: Option<usize>) -> Generated {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+pub fn solve_part<S: Solution>(input: &S::Input, part: u32, config: &S::Config) -> Answer {
+    match part {
+        1 => S::part1(input, config),
+        2 => S::part2(input, config),
+        _ => panic!("Invalid part {}", part),
+    }
+}
+
+// Parses and solves a single part with the day's default config, mostly for tests
+pub fn solve<S: Solution>(input: &str, part: u32) -> Result<Answer, ParseError> {
+    solve_with::<S>(input, part, &S::Config::default())
+}
+
+pub fn solve_with<S: Solution>(
+    input: &str,
+    part: u32,
+    config: &S::Config,
+) -> Result<Answer, ParseError> {
+    let parsed = S::parse(input, config).map_err(|e| e.locate(input))?;
+    Ok(solve_part::<S>(&parsed, part, config))
+}
+
+pub fn run<S: Solution>(
+    input: &str,
+    parts: &[u32],
+    config: &S::Config,
+) -> Result<Report, ParseError> {
+    let parse_time_start = Instant::now();
+    let parsed = S::parse(input, config).map_err(|e| e.locate(input))?;
+    let parse_time = parse_time_start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let time_start = Instant::now();
+            let answer = solve_part::<S>(&parsed, part, config);
+            PartResult {
+                part,
+                answer,
+                time: time_start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report { parse_time, parts })
+}
@@ -0,0 +1,210 @@
+use crate::answers::input_hash;
+use crate::input::normalize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use toml::{Table, Value};
+
+// Whose inputs to use when neither --user nor AOC_USER says
+pub const DEFAULT_USER: &str = "default";
+
+// Puzzle inputs kept outside the repository, one directory per user:
+//
+// ~/.cache/aoc/2021/alice/day01.txt
+// ~/.cache/aoc/2021/alice/inputs.toml
+//
+// inputs.toml holds the SHA-256 of each input as it was imported. Answers and timings are
+// recorded against that hash, so an input that changes afterwards is refused rather than
+// silently measured as if it were the same one.
+#[derive(Debug, Clone)]
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> InputStore {
+        InputStore { root: root.into() }
+    }
+
+    // AOC_CACHE_DIR replaces the whole location, otherwise it lives in the XDG cache directory
+    pub fn open() -> Result<InputStore, String> {
+        if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+            return Ok(InputStore::new(dir));
+        }
+
+        let cache = match (env::var_os("XDG_CACHE_HOME"), env::var_os("HOME")) {
+            (Some(dir), _) => PathBuf::from(dir),
+            (None, Some(home)) => PathBuf::from(home).join(".cache"),
+            (None, None) => {
+                return Err("Unable to find the input store, set HOME or AOC_CACHE_DIR".to_string())
+            }
+        };
+        Ok(InputStore::new(cache.join("aoc").join("2021")))
+    }
+
+    pub fn default_user() -> String {
+        env::var("AOC_USER")
+            .ok()
+            .filter(|user| !user.is_empty())
+            .unwrap_or_else(|| DEFAULT_USER.to_string())
+    }
+
+    // User names become directory names, so they are kept to something that can't escape
+    // the store
+    pub fn check_user(user: &str) -> Result<(), String> {
+        let valid = user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if valid && !user.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid user '{}', expected letters, digits, - or _",
+                user
+            ))
+        }
+    }
+
+    pub fn path(&self, user: &str, day: usize) -> PathBuf {
+        self.root.join(user).join(format!("day{:02}.txt", day))
+    }
+
+    fn manifest_path(&self, user: &str) -> PathBuf {
+        self.root.join(user).join("inputs.toml")
+    }
+
+    // A missing manifest is an empty one
+    fn load_manifest(&self, user: &str) -> Result<Table, String> {
+        let path = self.manifest_path(user);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Table::new()),
+            Err(e) => return Err(format!("Unable to read {}: {}", path.display(), e)),
+        };
+        text.parse::<Table>()
+            .map_err(|e| format!("Invalid input manifest {}: {}", path.display(), e))
+    }
+
+    pub fn contains(&self, user: &str, day: usize) -> bool {
+        self.path(user, day).is_file()
+    }
+
+    // The hash the input had when it was imported
+    pub fn hash(&self, user: &str, day: usize) -> Result<Option<String>, String> {
+        let manifest = self.load_manifest(user)?;
+        Ok(manifest
+            .get(&format!("day{:02}", day))
+            .and_then(|entry| entry.get("sha256"))
+            .and_then(|hash| hash.as_str())
+            .map(|hash| hash.to_string()))
+    }
+
+    // Copies an input into the store, replacing any earlier one for the day, and returns
+    // where it went along with its hash
+    pub fn import(&self, user: &str, day: usize, input: &str) -> Result<(PathBuf, String), String> {
+        InputStore::check_user(user)?;
+        let input = normalize(input.to_string());
+        let hash = input_hash(&input);
+
+        let path = self.path(user, day);
+        let write = |path: &PathBuf, text: &str| {
+            fs::write(path, text).map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+        };
+        let dir = self.root.join(user);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Unable to create {}: {}", dir.display(), e))?;
+        write(&path, &input)?;
+
+        let mut manifest = self.load_manifest(user)?;
+        let mut entry = Table::new();
+        entry.insert("sha256".to_string(), Value::String(hash.clone()));
+        manifest.insert(format!("day{:02}", day), Value::Table(entry));
+        write(&self.manifest_path(user), &manifest.to_string())?;
+
+        Ok((path, hash))
+    }
+
+    // Reads a stored input, checking it against the hash it was imported with
+    pub fn read(&self, user: &str, day: usize) -> Result<(PathBuf, String), String> {
+        InputStore::check_user(user)?;
+        let path = self.path(user, day);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => normalize(text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(format!(
+                    "No day {} input for '{}' in {}, add one with: aoc import {} <file> --user {}",
+                    day,
+                    user,
+                    self.root.display(),
+                    day,
+                    user
+                ))
+            }
+            Err(e) => return Err(format!("Unable to read {}: {}", path.display(), e)),
+        };
+
+        let actual = input_hash(&text);
+        match self.hash(user, day)? {
+            Some(expected) if expected == actual => Ok((path, text)),
+            Some(expected) => Err(format!(
+                "{} has changed since it was imported (sha256 {} is now {}), import it again \
+                 if that was intended",
+                path.display(),
+                expected,
+                actual
+            )),
+            None => Err(format!(
+                "{} has no recorded hash, add it with aoc import instead of copying it in",
+                path.display()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    fn temp_store(name: &str) -> InputStore {
+        let root = env::temp_dir().join(format!("aoc-store-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        InputStore::new(root)
+    }
+
+    #[test]
+    fn imports_and_reads_back() {
+        let store = temp_store("round-trip");
+        let (path, hash) = store.import("alice", 3, "00100\r\n11110\r\n").unwrap();
+        assert!(path.ends_with("alice/day03.txt"));
+        assert_eq!(hash, input_hash("00100\n11110\n"));
+
+        let (_, text) = store.read("alice", 3).unwrap();
+        assert_eq!(text, "00100\n11110\n");
+        assert_eq!(store.hash("alice", 3), Ok(Some(hash)));
+        assert!(!store.contains("bob", 3));
+        assert!(store.read("bob", 3).is_err());
+
+        fs::remove_dir_all(&store.root).unwrap();
+    }
+
+    #[test]
+    fn refuses_changed_inputs() {
+        let store = temp_store("tampered");
+        let (path, _) = store.import("alice", 1, "199\n200\n").unwrap();
+        fs::write(&path, "199\n201\n").unwrap();
+
+        let error = store.read("alice", 1).unwrap_err();
+        assert!(error.contains("has changed since it was imported"));
+
+        fs::remove_dir_all(&store.root).unwrap();
+    }
+
+    #[test]
+    fn checks_user_names() {
+        assert!(InputStore::check_user("alice_2").is_ok());
+        assert!(InputStore::check_user("").is_err());
+        assert!(InputStore::check_user("../bob").is_err());
+    }
+}
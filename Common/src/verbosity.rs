@@ -0,0 +1,69 @@
+use std::io::{self, IsTerminal};
+use tracing::level_filters::LevelFilter;
+
+// How much gets printed besides the answers. Solvers report what they are doing through
+// tracing, with debug! for a line per step of a simulation and trace! for the whole state
+// at each step. It all goes to stderr, so answers on stdout can still be piped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    // -q: the answers and nothing else, not even warnings
+    Quiet,
+    #[default]
+    Normal,
+    // -v: a line per step
+    Verbose,
+    // -vv: the full state at every step
+    Trace,
+}
+
+impl Verbosity {
+    // Takes -q, -v and -vv (or -v twice), returning false for any other argument
+    pub fn apply_flag(&mut self, arg: &str) -> bool {
+        *self = match (arg, *self) {
+            ("-q" | "--quiet", _) => Verbosity::Quiet,
+            ("-v" | "--verbose", Verbosity::Verbose | Verbosity::Trace) => Verbosity::Trace,
+            ("-v" | "--verbose", _) => Verbosity::Verbose,
+            ("-vv", _) => Verbosity::Trace,
+            _ => return false,
+        };
+        true
+    }
+
+    pub fn is_quiet(self) -> bool {
+        self == Verbosity::Quiet
+    }
+
+    // Installs the logger for the whole process. Only the first call has any effect.
+    pub fn init(self) {
+        let level = match self {
+            Verbosity::Quiet => LevelFilter::OFF,
+            Verbosity::Normal => LevelFilter::WARN,
+            Verbosity::Verbose => LevelFilter::DEBUG,
+            Verbosity::Trace => LevelFilter::TRACE,
+        };
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .without_time()
+            .with_target(false)
+            .try_init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_raise_and_lower_verbosity() {
+        let mut verbosity = Verbosity::default();
+        assert!(verbosity.apply_flag("-v"));
+        assert_eq!(verbosity, Verbosity::Verbose);
+        assert!(verbosity.apply_flag("-v"));
+        assert_eq!(verbosity, Verbosity::Trace);
+        assert!(verbosity.apply_flag("-q"));
+        assert!(verbosity.is_quiet());
+        assert!(!verbosity.apply_flag("--part"));
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, BufRead, Read};
+use std::str::FromStr;
+
+// How the readings in a window are combined into the one value windows are compared by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median,
+}
+
+impl FromStr for Aggregation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Aggregation, String> {
+        match s {
+            "sum" => Ok(Aggregation::Sum),
+            "mean" => Ok(Aggregation::Mean),
+            "min" => Ok(Aggregation::Min),
+            "max" => Ok(Aggregation::Max),
+            "median" => Ok(Aggregation::Median),
+            _ => Err(format!(
+                "Invalid aggregation '{}', expected sum, mean, min, max or median",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Mean => "mean",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+            Aggregation::Median => "median",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// A stretch of windows that each went the same way as the one before. `start` is the index
+// of the window it starts from, and it ends `steps` windows later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Run {
+    pub start: usize,
+    pub steps: usize,
+}
+
+// The change from one window to the next, along with the index of the later window. The
+// windows are compared exactly, and the change is only rounded to an f64 for showing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jump {
+    pub window: usize,
+    pub change: f64,
+}
+
+// What the windows did from one to the next. Ties for the longest runs and largest jumps
+// go to the first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DepthStats {
+    pub readings: usize,
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    pub longest_rise: Run,
+    pub longest_fall: Run,
+    pub largest_rise: Option<Jump>,
+    pub largest_drop: Option<Jump>,
+}
+
+// Takes readings one at a time and keeps the stats up to date, holding on to no more than a
+// window's worth of readings
+#[derive(Debug, Clone)]
+pub struct DepthAnalyzer {
+    window: usize,
+    aggregation: Aggregation,
+    readings: VecDeque<i64>,
+    // Only the one the aggregation needs is kept up to date: the running sum, the candidates
+    // for the minimum or maximum as (index, reading), or the window's readings in order
+    sum: i128,
+    extremes: VecDeque<(usize, i64)>,
+    sorted: Vec<i64>,
+    // The last window's value and the largest changes so far, exact and scaled as by value()
+    previous: Option<i128>,
+    largest_rise: Option<i128>,
+    largest_drop: Option<i128>,
+    rise: Run,
+    fall: Run,
+    stats: DepthStats,
+}
+
+impl DepthAnalyzer {
+    pub fn new(window: usize, aggregation: Aggregation) -> DepthAnalyzer {
+        assert!(window > 0, "Empty window");
+        DepthAnalyzer {
+            window,
+            aggregation,
+            readings: VecDeque::with_capacity(window + 1),
+            sum: 0,
+            extremes: VecDeque::new(),
+            sorted: Vec::new(),
+            previous: None,
+            largest_rise: None,
+            largest_drop: None,
+            rise: Run::default(),
+            fall: Run::default(),
+            stats: DepthStats::default(),
+        }
+    }
+
+    pub fn push(&mut self, depth: i64) {
+        let index = self.stats.readings;
+        self.stats.readings += 1;
+        self.readings.push_back(depth);
+        match self.aggregation {
+            Aggregation::Sum | Aggregation::Mean => self.sum += depth as i128,
+            // A reading can't be the minimum while a later, smaller one is in the window
+            Aggregation::Min => {
+                while self.extremes.back().is_some_and(|&(_, d)| d >= depth) {
+                    self.extremes.pop_back();
+                }
+                self.extremes.push_back((index, depth));
+            }
+            Aggregation::Max => {
+                while self.extremes.back().is_some_and(|&(_, d)| d <= depth) {
+                    self.extremes.pop_back();
+                }
+                self.extremes.push_back((index, depth));
+            }
+            Aggregation::Median => {
+                let at = self.sorted.partition_point(|&d| d < depth);
+                self.sorted.insert(at, depth);
+            }
+        }
+
+        if self.readings.len() > self.window {
+            let old = self.readings.pop_front().unwrap();
+            match self.aggregation {
+                Aggregation::Sum | Aggregation::Mean => self.sum -= old as i128,
+                Aggregation::Min | Aggregation::Max => {
+                    if self.extremes[0].0 + self.window <= index {
+                        self.extremes.pop_front();
+                    }
+                }
+                Aggregation::Median => {
+                    let at = self.sorted.binary_search(&old).unwrap();
+                    self.sorted.remove(at);
+                }
+            }
+        }
+
+        if self.readings.len() == self.window {
+            let value = self.value();
+            self.record(value);
+        }
+    }
+
+    // The window's value, exactly and scaled by scale(): means are compared by their sums, as
+    // every window is the same size, and a median of two readings by their sum
+    fn value(&self) -> i128 {
+        match self.aggregation {
+            Aggregation::Sum | Aggregation::Mean => self.sum,
+            Aggregation::Min | Aggregation::Max => self.extremes[0].1 as i128,
+            Aggregation::Median => {
+                let mid = self.window / 2;
+                if self.window % 2 == 1 {
+                    self.sorted[mid] as i128
+                } else {
+                    self.sorted[mid - 1] as i128 + self.sorted[mid] as i128
+                }
+            }
+        }
+    }
+
+    fn scale(&self) -> f64 {
+        match self.aggregation {
+            Aggregation::Mean => self.window as f64,
+            Aggregation::Median if self.window.is_multiple_of(2) => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    fn record(&mut self, value: i128) {
+        let window = self.stats.windows;
+        self.stats.windows += 1;
+        let previous = match self.previous.replace(value) {
+            Some(previous) => previous,
+            None => return,
+        };
+
+        let change = value - previous;
+        let jump = Jump {
+            window,
+            change: change as f64 / self.scale(),
+        };
+        let stats = &mut self.stats;
+        if change > 0 {
+            stats.increases += 1;
+            extend(&mut self.rise, &mut stats.longest_rise, window);
+            self.fall.steps = 0;
+            if self.largest_rise.is_none_or(|largest| change > largest) {
+                self.largest_rise = Some(change);
+                stats.largest_rise = Some(jump);
+            }
+        } else if change < 0 {
+            stats.decreases += 1;
+            extend(&mut self.fall, &mut stats.longest_fall, window);
+            self.rise.steps = 0;
+            if self.largest_drop.is_none_or(|largest| change < largest) {
+                self.largest_drop = Some(change);
+                stats.largest_drop = Some(jump);
+            }
+        } else {
+            stats.plateaus += 1;
+            self.rise.steps = 0;
+            self.fall.steps = 0;
+        }
+    }
+
+    pub fn stats(&self) -> &DepthStats {
+        &self.stats
+    }
+}
+
+// A summary of the windows so far, a line per statistic, for showing under an answer
+impl fmt::Display for DepthAnalyzer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stats = &self.stats;
+        writeln!(
+            f,
+            "{} readings in {} windows of {} ({})",
+            stats.readings, stats.windows, self.window, self.aggregation
+        )?;
+        writeln!(
+            f,
+            "{} increases, {} decreases, {} plateaus",
+            stats.increases, stats.decreases, stats.plateaus
+        )?;
+        for (name, run) in [("rise", stats.longest_rise), ("fall", stats.longest_fall)] {
+            match run.steps {
+                0 => writeln!(f, "Longest {}: none", name)?,
+                steps => writeln!(
+                    f,
+                    "Longest {}: windows {} to {}",
+                    name,
+                    run.start,
+                    run.start + steps
+                )?,
+            }
+        }
+        for (name, jump) in [("rise", stats.largest_rise), ("drop", stats.largest_drop)] {
+            match jump {
+                Some(jump) => writeln!(
+                    f,
+                    "Largest {}: {:+} into window {}",
+                    name, jump.change, jump.window
+                )?,
+                None => writeln!(f, "Largest {}: none", name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Adds the step into `window` to the current run, which may now be the longest
+fn extend(current: &mut Run, longest: &mut Run, window: usize) {
+    if current.steps == 0 {
+        current.start = window - 1;
+    }
+    current.steps += 1;
+    if current.steps > longest.steps {
+        *longest = *current;
+    }
+}
+
+pub fn analyze(
+    depths: impl IntoIterator<Item = i64>,
+    window: usize,
+    aggregation: Aggregation,
+) -> DepthStats {
+    let mut analyzer = DepthAnalyzer::new(window, aggregation);
+    for depth in depths {
+        analyzer.push(depth);
+    }
+    analyzer.stats
+}
+
+// The depths in a sensor log, read a line at a time. Lines that are blank or aren't a whole
+// number, including ones that aren't UTF-8, are skipped and counted rather than stopping the
+// read, as long logs are expected to have some. Lines longer than MAX_LINE are skipped
+// without being read into memory.
+pub struct Readings<R> {
+    reader: R,
+    line: Vec<u8>,
+    skipped: usize,
+}
+
+impl<R: BufRead> Readings<R> {
+    pub fn new(reader: R) -> Readings<R> {
+        Readings {
+            reader,
+            line: Vec::new(),
+            skipped: 0,
+        }
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+// Far longer than any depth, even padded with whitespace
+const MAX_LINE: u64 = 256;
+
+// Reads past the rest of the line, a buffer at a time
+fn skip_line(reader: &mut impl BufRead) -> io::Result<()> {
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        match buffer.iter().position(|&b| b == b'\n') {
+            Some(end) => {
+                reader.consume(end + 1);
+                return Ok(());
+            }
+            None => {
+                let len = buffer.len();
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Readings<R> {
+    type Item = io::Result<i64>;
+
+    fn next(&mut self) -> Option<io::Result<i64>> {
+        loop {
+            self.line.clear();
+            let mut limited = (&mut self.reader).take(MAX_LINE);
+            match limited.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+            if self.line.len() as u64 == MAX_LINE && self.line.last() != Some(&b'\n') {
+                if let Err(e) = skip_line(&mut self.reader) {
+                    return Some(Err(e));
+                }
+                self.skipped += 1;
+                continue;
+            }
+            let depth = std::str::from_utf8(&self.line)
+                .ok()
+                .and_then(|line| line.trim().parse().ok());
+            match depth {
+                Some(depth) => return Some(Ok(depth)),
+                None => self.skipped += 1,
+            }
+        }
+    }
+}
+
+// Feeds every depth in the log to each analyzer, in one pass, and returns how many lines
+// were skipped
+pub fn analyze_log(reader: impl BufRead, analyzers: &mut [DepthAnalyzer]) -> io::Result<usize> {
+    let mut readings = Readings::new(reader);
+    for depth in &mut readings {
+        let depth = depth?;
+        for analyzer in analyzers.iter_mut() {
+            analyzer.push(depth);
+        }
+    }
+    Ok(readings.skipped())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn example_windows() {
+        let stats = analyze(EXAMPLE, 3, Aggregation::Sum);
+        assert_eq!(stats.windows, 8);
+        assert_eq!(
+            (stats.increases, stats.decreases, stats.plateaus),
+            (5, 1, 1)
+        );
+        assert_eq!(stats.longest_rise, Run { start: 3, steps: 4 });
+        assert_eq!(stats.longest_fall, Run { start: 2, steps: 1 });
+        assert_eq!(
+            stats.largest_rise,
+            Some(Jump {
+                window: 5,
+                change: 69.0
+            })
+        );
+
+        let stats = analyze(EXAMPLE, 2, Aggregation::Median);
+        assert_eq!(
+            stats.largest_drop,
+            Some(Jump {
+                window: 3,
+                change: -4.0
+            })
+        );
+    }
+
+    #[test]
+    fn reports_stats() {
+        let mut analyzer = DepthAnalyzer::new(2, Aggregation::Median);
+        for depth in EXAMPLE {
+            analyzer.push(depth);
+        }
+        assert_eq!(
+            analyzer.to_string(),
+            "10 readings in 9 windows of 2 (median)\n\
+             5 increases, 3 decreases, 0 plateaus\n\
+             Longest rise: windows 4 to 7\n\
+             Longest fall: windows 2 to 4\n\
+             Largest rise: +31 into window 6\n\
+             Largest drop: -4 into window 3\n"
+        );
+
+        let analyzer = DepthAnalyzer::new(3, Aggregation::Sum);
+        assert_eq!(
+            analyzer.to_string(),
+            "0 readings in 0 windows of 3 (sum)\n\
+             0 increases, 0 decreases, 0 plateaus\n\
+             Longest rise: none\n\
+             Longest fall: none\n\
+             Largest rise: none\n\
+             Largest drop: none\n"
+        );
+    }
+
+    #[test]
+    fn skips_unreadable_lines() {
+        let log: &[u8] = b"10\n\n-3\r\nping\n\xff\xfe\n 7 \n12.5\n9";
+        let mut analyzers = [
+            DepthAnalyzer::new(1, Aggregation::Sum),
+            DepthAnalyzer::new(2, Aggregation::Min),
+        ];
+        assert_eq!(analyze_log(log, &mut analyzers).unwrap(), 4);
+
+        // Left with 10, -3, 7 and 9
+        let stats = analyzers[0].stats();
+        assert_eq!(stats.readings, 4);
+        assert_eq!((stats.increases, stats.decreases), (2, 1));
+        let stats = analyzers[1].stats();
+        assert_eq!((stats.increases, stats.plateaus), (1, 1));
+    }
+
+    #[test]
+    fn huge_depths_stay_exact() {
+        // Neighbours that an f64 can't tell apart
+        let depths = [
+            i64::MAX - 4,
+            i64::MAX - 3,
+            i64::MAX - 2,
+            i64::MAX - 1,
+            i64::MAX,
+        ];
+        for aggregation in [
+            Aggregation::Sum,
+            Aggregation::Mean,
+            Aggregation::Min,
+            Aggregation::Max,
+            Aggregation::Median,
+        ] {
+            for window in 1..=2 {
+                let stats = analyze(depths, window, aggregation);
+                assert_eq!(stats.increases, 5 - window, "{} of {}", aggregation, window);
+            }
+        }
+
+        let log: &[u8] = b"9007199254740992\n9007199254740993\n9007199254740994\n";
+        let mut analyzers = [DepthAnalyzer::new(1, Aggregation::Sum)];
+        analyze_log(log, &mut analyzers).unwrap();
+        assert_eq!(analyzers[0].stats().increases, 2);
+    }
+
+    #[test]
+    fn skips_long_lines_unread() {
+        // A line of garbage much longer than MAX_LINE, with depths either side
+        let mut log = b"4\n".to_vec();
+        log.extend(std::iter::repeat_n(b'7', 10_000));
+        log.extend(b"\n5\n");
+        let mut readings = Readings::new(&log[..]);
+        let depths: Vec<i64> = readings.by_ref().map(|depth| depth.unwrap()).collect();
+        assert_eq!(depths, [4, 5]);
+        assert_eq!(readings.skipped(), 1);
+        assert!(readings.line.capacity() <= MAX_LINE as usize * 2);
+    }
+
+    // Aggregates every window from scratch, as exact multiples of 1 / (2 * window) so that
+    // means and medians of two readings are whole numbers too
+    fn naive_values(depths: &[i64], window: usize, aggregation: Aggregation) -> Vec<i128> {
+        let scale = 2 * window as i128;
+        depths
+            .windows(window)
+            .map(|w| {
+                let mut sorted: Vec<i128> = w.iter().map(|&d| d as i128).collect();
+                sorted.sort();
+                let sum: i128 = sorted.iter().sum();
+                match aggregation {
+                    Aggregation::Sum => sum * scale,
+                    Aggregation::Mean => sum * 2,
+                    Aggregation::Min => sorted[0] * scale,
+                    Aggregation::Max => sorted[window - 1] * scale,
+                    Aggregation::Median if window % 2 == 1 => sorted[window / 2] * scale,
+                    Aggregation::Median => {
+                        (sorted[window / 2 - 1] + sorted[window / 2]) * window as i128
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn aggregation() -> impl Strategy<Value = Aggregation> {
+        prop_oneof![
+            Just(Aggregation::Sum),
+            Just(Aggregation::Mean),
+            Just(Aggregation::Min),
+            Just(Aggregation::Max),
+            Just(Aggregation::Median),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_naive_windows(
+            depths in prop::collection::vec(-10i64..10, 0..60),
+            window in 1usize..6,
+            aggregation in aggregation(),
+        ) {
+            let values = naive_values(&depths, window, aggregation);
+            let changes: Vec<i128> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            let stats = analyze(depths.iter().copied(), window, aggregation);
+
+            prop_assert_eq!(stats.windows, values.len());
+            prop_assert_eq!(stats.increases, changes.iter().filter(|&&c| c > 0).count());
+            prop_assert_eq!(stats.decreases, changes.iter().filter(|&&c| c < 0).count());
+            let scale = 2.0 * window as f64;
+            let rise = changes.iter().copied().max().unwrap_or(0).max(0);
+            prop_assert_eq!(
+                stats.largest_rise.map_or(0.0, |jump| jump.change),
+                rise as f64 / scale
+            );
+            let drop = changes.iter().copied().min().unwrap_or(0).min(0);
+            prop_assert_eq!(
+                stats.largest_drop.map_or(0.0, |jump| jump.change),
+                drop as f64 / scale
+            );
+
+            let longest = changes
+                .chunk_by(|a, b| (*a > 0) == (*b > 0))
+                .filter(|run| run[0] > 0)
+                .map(|run| run.len())
+                .max()
+                .unwrap_or(0);
+            prop_assert_eq!(stats.longest_rise.steps, longest);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+// `size` is the number of depth readings
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut depth = rng.range(100..=200);
+    let depths = (0..size.unwrap_or(2000)).map(|_| {
+        // The sea floor mostly gets deeper
+        depth = (depth + rng.range(-10..=20)).max(0);
+        depth.to_string()
+    });
+    Generated::new(lines(depths))
+}
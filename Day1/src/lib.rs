@@ -0,0 +1,129 @@
+pub mod depth;
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use depth::Aggregation;
+
+aoc_common::config! {
+    pub struct Day1Config {
+        // Part 2 compares windows of this many readings, combined with part2_aggregation
+        part2_window: usize = 3,
+        part2_aggregation: Aggregation = Aggregation::Sum,
+    }
+    check = check_config;
+}
+
+fn check_config(config: &Day1Config) -> Result<(), String> {
+    if config.part2_window == 0 {
+        return Err("part2_window must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn part1(values: &[i64]) -> usize {
+    let mut num_increases = 0;
+    for i in 1..values.len() {
+        if values[i] > values[i - 1] {
+            num_increases += 1;
+        }
+    }
+    num_increases
+}
+
+// a0 + a1 + a2 < a1 + a2 + a3
+// a0 < a3
+// which holds for sums, and means, of any window size. Other aggregations have to look at
+// the whole window.
+fn part2(values: &[i64], window: usize, aggregation: Aggregation) -> usize {
+    if !matches!(aggregation, Aggregation::Sum | Aggregation::Mean) {
+        return depth::analyze(values.iter().copied(), window, aggregation).increases;
+    }
+
+    let mut num_increases = 0;
+    for i in window..values.len() {
+        if values[i] > values[i - window] {
+            num_increases += 1;
+        }
+    }
+    num_increases
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.lines().map(parse::number).collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Config = Day1Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(values: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(values).into()
+    }
+
+    fn part2(values: &Self::Input, config: &Self::Config) -> Answer {
+        part2(values, config.part2_window, config.part2_aggregation).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day1>(EXAMPLE, 1), Ok(Answer::Int(7)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day1>(EXAMPLE, 2), Ok(Answer::Int(5)));
+    }
+
+    #[test]
+    fn negative_depths() {
+        let input = "-5\n-3\n-4\n-1\n-2\n";
+        assert_eq!(solve::<Day1>(input, 1), Ok(Answer::Int(2)));
+        assert_eq!(solve::<Day1>(input, 2), Ok(Answer::Int(2)));
+    }
+
+    // Sums every window and compares neighbouring sums, without the a0 < a3 shortcut
+    fn naive_increases(values: &[i64], window: usize) -> usize {
+        let sums: Vec<i64> = values.windows(window).map(|w| w.iter().sum()).collect();
+        sums.windows(2).filter(|pair| pair[1] > pair[0]).count()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_summed_windows(values in prop::collection::vec(-300i64..300, 0..60)) {
+            prop_assert_eq!(part1(&values), naive_increases(&values, 1));
+            prop_assert_eq!(part2(&values, 3, Aggregation::Sum), naive_increases(&values, 3));
+        }
+
+        #[test]
+        fn shortcut_matches_analyzer(
+            values in prop::collection::vec(-300i64..300, 0..60),
+            window in 1usize..6,
+        ) {
+            for aggregation in [Aggregation::Sum, Aggregation::Mean] {
+                let stats = depth::analyze(values.iter().copied(), window, aggregation);
+                prop_assert_eq!(part2(&values, window, aggregation), stats.increases);
+            }
+        }
+    }
+}
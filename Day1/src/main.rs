@@ -1,67 +1,62 @@
-use std::fs;
-use std::io::Write;
-use std::time;
-
-fn part1(values: &[usize]) -> usize {
-    let mut num_increases = 0;
-    for i in 1..values.len() {
-        if values[i] > values[i - 1] {
-            num_increases += 1;
+use aoc_common::day::print_answer;
+use aoc_common::Settings;
+use day_1::depth::{self, Aggregation, DepthAnalyzer};
+use day_1::Day1Config;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process;
+
+// day_1 --stream <path|-> [--set <key=value>]...
+//
+// Counts both parts over a sensor log of any size a line at a time, rather than reading it
+// all in first like the other days, with the rest of each part's window stats under its
+// answer. Lines that aren't depths are skipped and counted.
+fn stream(args: &[String]) -> Result<(), String> {
+    let usage = "Usage: day_1 --stream <path|-> [--set <key=value>]...";
+    let (path, rest) = args.split_first().ok_or(usage)?;
+    let mut settings = Settings::default();
+    for pair in rest.chunks(2) {
+        match pair {
+            [flag, setting] if flag == "--set" || flag == "-s" => settings.push_arg(setting)?,
+            _ => return Err(usage.to_string()),
         }
     }
-    num_increases
-}
+    let config: Day1Config = settings.apply()?;
+
+    let mut analyzers = [
+        DepthAnalyzer::new(1, Aggregation::Sum),
+        DepthAnalyzer::new(config.part2_window, config.part2_aggregation),
+    ];
+    let skipped = if path == "-" {
+        depth::analyze_log(io::stdin().lock(), &mut analyzers)
+    } else {
+        let file = File::open(path).map_err(|e| format!("Unable to read {}: {}", path, e))?;
+        depth::analyze_log(BufReader::new(file), &mut analyzers)
+    }
+    .map_err(|e| format!("Unable to read {}: {}", path, e))?;
 
-// a0 + a1 + a2 < a1 + a2 + a3
-// a0 < a3
-fn part2(values: &[usize]) -> usize {
-    let mut num_increases = 0;
-    for i in 3..values.len() {
-        if values[i] > values[i - 3] {
-            num_increases += 1;
+    for (part, analyzer) in (1..).zip(&analyzers) {
+        print_answer(part, &analyzer.stats().increases.into());
+        for line in analyzer.to_string().lines() {
+            println!("    {}", line);
         }
     }
-    num_increases
+    if skipped > 0 {
+        eprintln!("Skipped {} lines that weren't depths", skipped);
+    }
+    Ok(())
 }
 
 fn main() {
-    let total_time_start = time::Instant::now();
-    let stdout = std::io::stdout();
-    let mut stdout_handle = stdout.lock();
-    writeln!(stdout_handle, "AoC 2021 Day 1").unwrap();
-    writeln!(stdout_handle, "----------------------------------\n").unwrap();
-
-    // Read all lines of input.txt
-    let read_time_start = time::Instant::now();
-    //let input = fs::read_to_string("example.txt").expect("Unable to read file");
-    let input = fs::read_to_string("input.txt").expect("Unable to read file");
-    let read_time = read_time_start.elapsed();
-    let convert_time_start = time::Instant::now();
-    let lines = input
-        .lines()
-        .map(|line| line.trim().parse::<usize>().unwrap())
-        .collect::<Vec<usize>>();
-    let convert_time = convert_time_start.elapsed();
-
-    let part1_time_start = time::Instant::now();
-    let part1_answer = part1(&lines);
-    let part1_time = part1_time_start.elapsed();
-    writeln!(stdout_handle, "Part 1 Answer: {}", part1_answer).unwrap();
-
-    writeln!(stdout_handle, "\n----------------------------------\n").unwrap();
-
-    let part2_time = time::Instant::now();
-    let part2_answer = part2(&lines);
-    let part2_time = part2_time.elapsed();
-    writeln!(stdout_handle, "Part 2 Answer: {}", part2_answer).unwrap();
-
-    writeln!(stdout_handle, "\n----------------------------------\n").unwrap();
-
-    writeln!(stdout_handle, "Read time: {:?}", read_time).unwrap();
-    writeln!(stdout_handle, "Convert time: {:?}", convert_time).unwrap();
-    writeln!(stdout_handle, "Part 1 time: {:?}", part1_time).unwrap();
-    writeln!(stdout_handle, "Part 2 time: {:?}", part2_time).unwrap();
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "--stream") {
+        if let Err(message) = stream(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        return;
+    }
 
-    let total_time = total_time_start.elapsed();
-    writeln!(stdout_handle, "Total time: {:?}", total_time).unwrap();
+    aoc_common::run_day::<day_1::Day1>(1);
 }
@@ -0,0 +1,57 @@
+use crate::MAX_DEPTH;
+use aoc_common::generate::{lines, Generated, Rng};
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// `size` is the number of lines. About half are corrupted and the rest are incomplete, with
+// an odd number of incomplete lines so part 2 has a middle score.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(100).max(1);
+    let mut incomplete = size / 2;
+    if incomplete.is_multiple_of(2) {
+        incomplete += 1;
+    }
+    let mut kinds: Vec<bool> = (0..size).map(|i| i < incomplete).collect();
+    rng.shuffle(&mut kinds);
+
+    let chunks = kinds.into_iter().map(|is_incomplete| {
+        let mut line = String::new();
+        let mut open = Vec::new();
+        for _ in 0..rng.range(20..=100) {
+            if open.is_empty() || (open.len() < MAX_DEPTH && rng.chance(0.55)) {
+                let (opener, closer) = *rng.choose(&PAIRS);
+                line.push(opener);
+                open.push(closer);
+            } else {
+                line.push(open.pop().unwrap());
+            }
+        }
+
+        if is_incomplete {
+            // Still has to be missing something at the end
+            if open.is_empty() {
+                line.push(rng.choose(&PAIRS).0);
+            }
+        } else {
+            if open.is_empty() {
+                let (opener, closer) = *rng.choose(&PAIRS);
+                line.push(opener);
+                open.push(closer);
+            }
+            let expected = *open.last().unwrap();
+            let wrong: Vec<char> = PAIRS
+                .iter()
+                .map(|&(_, closer)| closer)
+                .filter(|&closer| closer != expected)
+                .collect();
+            line.push(*rng.choose(&wrong));
+            // Anything can follow, the first illegal character is all that counts
+            for _ in 0..rng.range(0..=10) {
+                let (opener, closer) = *rng.choose(&PAIRS);
+                line.push(if rng.chance(0.5) { opener } else { closer });
+            }
+        }
+        line
+    });
+    Generated::new(lines(chunks))
+}
@@ -0,0 +1,207 @@
+mod generate;
+
+//use itertools::Itertools;
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use std::collections::HashMap;
+use tracing::trace;
+
+// Completion scores are 5^depth, so an incomplete line left with more open brackets than
+// this would overflow part 2's u64
+const MAX_DEPTH: usize = 27;
+
+fn part1(input: &[String]) -> u64 {
+    let mut map: HashMap<char, char> = HashMap::new();
+    map.insert('(', ')');
+    map.insert('{', '}');
+    map.insert('[', ']');
+    map.insert('<', '>');
+
+    let mut score = 0;
+    let mut stack: Vec<char> = Vec::new();
+    for line in input.iter() {
+        stack.clear();
+
+        for c in line.chars() {
+            if c == '(' || c == '[' || c == '{' || c == '<' {
+                stack.push(c);
+            } else {
+                // A closer with nothing open is as corrupt as the wrong closer
+                let endc = stack.pop().map(|last| map[&last]);
+                if endc != Some(c) {
+                    match c {
+                        ')' => score += 3,
+                        '}' => score += 1197,
+                        ']' => score += 57,
+                        '>' => score += 25137,
+                        _ => panic!("unmatched"),
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    score
+}
+
+fn part2(input: &[String]) -> u64 {
+    let mut map: HashMap<char, char> = HashMap::new();
+    map.insert('(', ')');
+    map.insert('{', '}');
+    map.insert('[', ']');
+    map.insert('<', '>');
+
+    let mut scores: Vec<u64> = Vec::new();
+    let mut stack: Vec<char> = Vec::new();
+    for line in input.iter() {
+        stack.clear();
+
+        let mut is_invalid = false;
+        for c in line.chars() {
+            if c == '(' || c == '[' || c == '{' || c == '<' {
+                stack.push(c);
+            } else {
+                let endc = stack.pop().map(|last| map[&last]);
+                if endc != Some(c) {
+                    is_invalid = true;
+                    break;
+                }
+            }
+        }
+        if is_invalid {
+            continue;
+        }
+
+        let mut cur_score = 0;
+        let score_str: String = stack.iter().collect();
+        while let Some(last) = stack.pop() {
+            cur_score *= 5;
+            match last {
+                '(' => cur_score += 1,
+                '[' => cur_score += 2,
+                '{' => cur_score += 3,
+                '<' => cur_score += 4,
+                _ => panic!("unmatched"),
+            }
+        }
+        trace!(
+            line = line.as_str(),
+            missing = score_str.as_str(),
+            score = cur_score
+        );
+
+        scores.push(cur_score);
+    }
+
+    if scores.is_empty() {
+        return 0;
+    }
+    scores.sort();
+
+    scores[scores.len() / 2]
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::new();
+    for line in input.lines().map(|line| line.trim()) {
+        if let Some(i) = line.find(|c| !"([{<)]}>".contains(c)) {
+            return Err(ParseError::new(parse::char_at(line, i), "a bracket"));
+        }
+        // Only the brackets left open at the end of an incomplete line are ever scored
+        let mut open = Vec::new();
+        let mut corrupted = false;
+        for c in line.chars() {
+            if let Some(pair) = "([{<".find(c) {
+                open.push(pair);
+            } else if open.pop() != ")]}>".find(c) {
+                corrupted = true;
+                break;
+            }
+        }
+        if !corrupted && open.len() > MAX_DEPTH {
+            let expected = format!("at most {} unclosed brackets", MAX_DEPTH);
+            return Err(ParseError::after(line, expected));
+        }
+        lines.push(line.to_string());
+    }
+    Ok(lines)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(lines).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day10>(EXAMPLE, 1), Ok(Answer::Int(26397)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day10>(EXAMPLE, 2), Ok(Answer::Int(288957)));
+    }
+
+    #[test]
+    fn copes_with_unbalanced_lines() {
+        // A stray closer is corruption, and unclosed brackets don't carry into the next line
+        assert_eq!(solve::<Day10>(")\n(]\n", 1), Ok(Answer::Int(60)));
+        assert_eq!(solve::<Day10>("((\n)", 1), Ok(Answer::Int(3)));
+        assert_eq!(solve::<Day10>("(]", 2), Ok(Answer::Int(0)));
+
+        let deepest = "<".repeat(27);
+        assert_eq!(
+            solve::<Day10>(&deepest, 2),
+            Ok(Answer::Int(7450580596923828124))
+        );
+        let error = solve::<Day10>(&(deepest.clone() + "<"), 2).unwrap_err();
+        assert_eq!(error.expected, "at most 27 unclosed brackets");
+
+        // Deeper lines are fine as long as they're closed again or corrupted
+        let balanced = "(".repeat(40) + &")".repeat(40);
+        let corrupted = "(".repeat(40) + "]";
+        let input = [balanced, corrupted, deepest].join("\n");
+        assert_eq!(solve::<Day10>(&input, 1), Ok(Answer::Int(57)));
+        assert_eq!(
+            solve::<Day10>(&input, 2),
+            Ok(Answer::Int(7450580596923828124))
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..50 {
+            let generated = Day10::generate(&mut Rng::new(seed), None);
+            for part in 1..=2 {
+                assert!(solve::<Day10>(&generated.input, part).is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::generate::{lines, Generated, Rng};
+use grid::Grid;
+
+// Random grids don't always end up flashing together, which part 2 relies on, so ones that
+// haven't within this many steps are thrown away
+const MAX_STEPS: usize = 2000;
+// Random grids much bigger than the puzzle's rarely sync at all, so past this side, or after
+// this many tries, a grid that's sure to sync is built instead
+const MAX_RANDOM_SIDE: usize = 10;
+const MAX_TRIES: usize = 100;
+
+fn random_grid(rng: &mut Rng, side: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            input += &rng.range(0..=9).to_string();
+        }
+        input.push('\n');
+    }
+    input
+}
+
+// Every octopus starts at `level` or one above it, with at least one above. The ones above
+// reach 10 first, after 9 - level steps, and each of their neighbors is then at 9, so the
+// flashes spread to the whole grid that step.
+fn synced_grid(rng: &mut Rng, side: usize) -> (String, usize) {
+    let level = rng.range(0..=8) as usize;
+    let mut levels = vec![level; side * side];
+    for energy in levels.iter_mut() {
+        if rng.chance(0.5) {
+            *energy += 1;
+        }
+    }
+    levels[rng.below(side * side)] = level + 1;
+
+    let rows = levels.chunks(side).map(|row| {
+        row.iter()
+            .map(|energy| energy.to_string())
+            .collect::<String>()
+    });
+    (lines(rows), 9 - level)
+}
+
+// `size` is the side of the square grid of octopuses. Grids up to the puzzle's size are
+// random and run until they sync, so the part 2 answer is known. Bigger ones are built to
+// sync.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let side = size.unwrap_or(10).max(1);
+    if side <= MAX_RANDOM_SIDE {
+        for _ in 0..MAX_TRIES {
+            let input = random_grid(rng, side);
+            let mut grid = Grid::parse_digits(&input).unwrap();
+            let synced = (1..=MAX_STEPS).find(|&step| super::step(&mut grid, step) == side * side);
+            if let Some(steps) = synced {
+                return Generated::new(input).with_answer(2, steps);
+            }
+        }
+    }
+
+    let (input, steps) = synced_grid(rng, side);
+    Generated::new(input).with_answer(2, steps)
+}
@@ -0,0 +1,154 @@
+mod generate;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+use grid::Grid;
+use tracing::{debug, trace};
+
+aoc_common::config! {
+    pub struct Day11Config {
+        // Steps to count flashes over in part 1
+        part1_steps: usize = 100,
+    }
+}
+
+fn part1(input: &Grid<u8>, num_steps: usize) -> u64 {
+    let mut input = input.clone();
+    (1..=num_steps)
+        .map(|step_num| step(&mut input, step_num) as u64)
+        .sum()
+}
+
+fn part2(input: &Grid<u8>) -> u64 {
+    let mut input = input.clone();
+    let mut step_num = 1;
+    while step(&mut input, step_num) < input.cells().len() {
+        step_num += 1;
+    }
+    step_num as u64
+}
+
+// Advances the octopuses by one step and returns how many flashed
+fn step(input: &mut Grid<u8>, step_num: usize) -> usize {
+    for energy in input.cells_mut() {
+        *energy += 1;
+    }
+
+    let mut flashed = Grid::new(input.width(), input.height(), false);
+    let mut had_flash;
+    loop {
+        had_flash = false;
+        for pos in input.positions() {
+            if flashed[pos] || input[pos] <= 9 {
+                continue;
+            }
+
+            for adjacent in input.neighbors8(pos) {
+                input[adjacent] += 1;
+            }
+            flashed[pos] = true;
+            had_flash = true;
+        }
+
+        if !had_flash {
+            break;
+        }
+    }
+
+    for pos in input.positions() {
+        if flashed[pos] {
+            input[pos] = 0;
+        }
+    }
+    let flashes = flashed.cells().iter().filter(|&&x| x).count();
+    debug!(step = step_num, flashes);
+    trace!("after step {}:\n{}", step_num, input);
+    animate::frame(|| {
+        let rows = input
+            .rows()
+            .zip(flashed.rows())
+            .map(|(energies, flashes)| {
+                let cells = energies.iter().zip(flashes).map(|(&energy, &flashed)| {
+                    let c = char::from_digit(energy as u32, 10).unwrap_or('?');
+                    (c, flashed.then_some(Color::Yellow))
+                });
+                cells.collect()
+            })
+            .collect();
+        Frame::new(format!("Step {}: {} flashes", step_num, flashes), rows)
+    });
+    flashes
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+    type Config = Day11Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(energy_levels: &Self::Input, config: &Self::Config) -> Answer {
+        part1(energy_levels, config.part1_steps).into()
+    }
+
+    fn part2(energy_levels: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(energy_levels).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_with};
+    use std::time::{Duration, Instant};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day11>(EXAMPLE, 1), Ok(Answer::Int(1656)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day11>(EXAMPLE, 2), Ok(Answer::Int(195)));
+    }
+
+    #[test]
+    fn after_10_steps() {
+        let config = Day11Config { part1_steps: 10 };
+        assert_eq!(
+            solve_with::<Day11>(EXAMPLE, 1, &config),
+            Ok(Answer::Int(204))
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..5 {
+            for size in [6, 30] {
+                let generated = Day11::generate(&mut Rng::new(seed), Some(size));
+                for (part, answer) in &generated.answers {
+                    assert_eq!(solve::<Day11>(&generated.input, *part).as_ref(), Ok(answer));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generates_large_grids_quickly() {
+        let start = Instant::now();
+        let generated = Day11::generate(&mut Rng::new(1), Some(2000));
+        assert_eq!(generated.input.len(), 2000 * 2001);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,74 @@
+use super::MAX_ROOMS;
+use aoc_common::generate::{lines, Generated, Rng};
+use std::collections::HashSet;
+
+fn cave_name(rng: &mut Rng, big: bool, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..2)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        let name = if big { name.to_uppercase() } else { name };
+        if name != "start" && name != "end" && taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// The number of paths grows exponentially with the loops in the map and with how many small
+// caves each big one leads to, since the big ones can be passed through again and again. The
+// maps are kept sparse enough that even the largest usually has under a million paths, and
+// at worst millions rather than billions.
+const MAX_BIG_NEIGHBORS: usize = 3;
+// One edge beyond the spanning tree for every this many caves
+const CAVES_PER_LOOP: usize = 3;
+
+// `size` is the number of caves besides start and end, up to what the parser allows. Two big
+// caves are never connected, since there would be infinitely many paths between them.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(10).min(MAX_ROOMS - 2);
+    let mut taken = HashSet::new();
+    let mut caves = vec![("start".to_string(), false), ("end".to_string(), false)];
+    for _ in 0..size {
+        let big = rng.chance(0.25);
+        caves.push((cave_name(rng, big, &mut taken), big));
+    }
+    rng.shuffle(&mut caves[1..]);
+
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    let neighbors = |edges: &[(usize, usize)], cave: usize| {
+        edges
+            .iter()
+            .filter(|&&(a, b)| a == cave || b == cave)
+            .count()
+    };
+    // Two big caves next to each other would allow endless paths
+    let can_connect = |edges: &[(usize, usize)], a: usize, b: usize| {
+        a != b
+            && (!caves[a].1 || !caves[b].1)
+            && [a, b]
+                .iter()
+                .all(|&c| !caves[c].1 || neighbors(edges, c) < MAX_BIG_NEIGHBORS)
+            && !edges.contains(&(a, b))
+            && !edges.contains(&(b, a))
+    };
+
+    // A spanning tree keeps every cave reachable from start
+    for cave in 1..caves.len() {
+        let candidates: Vec<usize> = (0..cave)
+            .filter(|&other| can_connect(&edges, cave, other))
+            .collect();
+        edges.push((*rng.choose(&candidates), cave));
+    }
+    for _ in 0..size / CAVES_PER_LOOP {
+        let (a, b) = (rng.below(caves.len()), rng.below(caves.len()));
+        if can_connect(&edges, a, b) {
+            edges.push((a, b));
+        }
+    }
+    rng.shuffle(&mut edges);
+
+    let edges = edges
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", caves[a].0, caves[b].0));
+    Generated::new(lines(edges))
+}
@@ -0,0 +1,194 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use search::count_paths;
+use std::collections::HashMap;
+
+// Room ids are bits in Walk::visited
+const MAX_ROOMS: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+struct Walk {
+    room: usize,
+    visited: u64,
+    // Whether a small cave has been visited twice already
+    revisited: bool,
+}
+
+// Counts the paths from start to end that pass through small caves at most once, except that
+// one small cave may be visited twice if `allow_revisit` is set
+fn count_walks(rooms: &[Room], allow_revisit: bool) -> u64 {
+    let start = Walk {
+        room: 0,
+        visited: 1,
+        revisited: !allow_revisit,
+    };
+
+    let successors = |&walk: &Walk| {
+        rooms[walk.room]
+            .neighbors
+            .iter()
+            .filter(|&&next_room_id| next_room_id != 0)
+            .filter_map(move |&next_room_id| {
+                let seen = walk.visited & (1 << next_room_id) != 0;
+                let revisit = rooms[next_room_id].is_small_room && seen;
+                if revisit && walk.revisited {
+                    return None;
+                }
+
+                Some(Walk {
+                    room: next_room_id,
+                    visited: walk.visited | (1 << next_room_id),
+                    revisited: walk.revisited || revisit,
+                })
+            })
+    };
+
+    count_paths(start, successors, |walk| walk.room == 1)
+}
+
+fn part1(rooms: &[Room]) -> u64 {
+    count_walks(rooms, false)
+}
+
+fn part2(rooms: &[Room]) -> u64 {
+    count_walks(rooms, true)
+}
+
+fn is_small_room(room_name: &str) -> bool {
+    room_name.chars().all(|c| c.is_lowercase())
+}
+
+#[derive(Debug)]
+pub struct Room {
+    neighbors: Vec<usize>,
+    is_small_room: bool,
+}
+
+impl Room {
+    pub fn new(name: &str) -> Room {
+        Room {
+            neighbors: Vec::new(),
+            is_small_room: is_small_room(name),
+        }
+    }
+}
+
+fn make_room_vec(input: &[&str]) -> Result<Vec<Room>, ParseError> {
+    let mut room_name_to_id: HashMap<&str, usize> = HashMap::new();
+    room_name_to_id.insert("start", 0);
+    room_name_to_id.insert("end", 1);
+    let mut rooms = Vec::new();
+    rooms.push(Room::new("start"));
+    rooms.push(Room::new("end"));
+
+    for line in input {
+        let (name0, name1) = parse::split_once(line, "-")?;
+        for name in [name0, name1] {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(name, "a cave name"));
+            }
+        }
+
+        let id0 = if room_name_to_id.contains_key(name0) {
+            room_name_to_id[name0]
+        } else {
+            let id = rooms.len();
+            room_name_to_id.insert(name0, id);
+            rooms.push(Room::new(name0));
+            id
+        };
+
+        let id1 = if room_name_to_id.contains_key(name1) {
+            room_name_to_id[name1]
+        } else {
+            let id = rooms.len();
+            room_name_to_id.insert(name1, id);
+            rooms.push(Room::new(name1));
+            id
+        };
+
+        if rooms.len() > MAX_ROOMS {
+            return Err(ParseError::new(
+                line,
+                format!("at most {} caves", MAX_ROOMS),
+            ));
+        }
+
+        rooms[id0].neighbors.push(id1);
+        rooms[id1].neighbors.push(id0);
+    }
+
+    Ok(rooms)
+}
+
+fn parse(input: &str) -> Result<Vec<Room>, ParseError> {
+    let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+    make_room_vec(&lines)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Room>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] =
+        aoc_common::examples!("example1.txt", "example2.txt", "example3.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rooms: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(rooms).into()
+    }
+
+    fn part2(rooms: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(rooms).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    #[test]
+    fn part1_example1() {
+        assert_eq!(solve::<Day12>(EXAMPLE1, 1), Ok(Answer::Int(10)));
+    }
+
+    #[test]
+    fn part2_example1() {
+        assert_eq!(solve::<Day12>(EXAMPLE1, 2), Ok(Answer::Int(36)));
+    }
+
+    #[test]
+    fn part1_example2() {
+        assert_eq!(solve::<Day12>(EXAMPLE2, 1), Ok(Answer::Int(19)));
+    }
+
+    #[test]
+    fn part2_example2() {
+        assert_eq!(solve::<Day12>(EXAMPLE2, 2), Ok(Answer::Int(103)));
+    }
+
+    #[test]
+    fn part1_example3() {
+        assert_eq!(solve::<Day12>(EXAMPLE3, 1), Ok(Answer::Int(226)));
+    }
+
+    #[test]
+    fn part2_example3() {
+        assert_eq!(solve::<Day12>(EXAMPLE3, 2), Ok(Answer::Int(3509)));
+    }
+}
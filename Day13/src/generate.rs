@@ -0,0 +1,60 @@
+use aoc_common::generate::{lines, Generated, Rng};
+use std::collections::HashSet;
+
+const FOLDS: usize = 12;
+
+// `size` is the number of dots, at least one for each lit cell of the code. The paper is built
+// backwards from an 8 letter wide code, unfolding it once for every fold, so every fold halves
+// the paper exactly and no dot ever lands on a fold line.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let (mut width, mut height) = (40, 6);
+    // In the order they are unfolded, which is the reverse of the order they are made
+    let mut folds = Vec::new();
+    for i in 0..FOLDS {
+        if i % 2 == 0 {
+            folds.push(('y', height));
+            height = height * 2 + 1;
+        } else {
+            folds.push(('x', width));
+            width = width * 2 + 1;
+        }
+    }
+
+    // The code itself, which every dot folds back onto
+    let mut code = Vec::new();
+    for x in 0..40 {
+        for y in 0..6 {
+            if rng.chance(0.4) {
+                code.push((x, y));
+            }
+        }
+    }
+    if code.is_empty() {
+        code.push((0, 0));
+    }
+
+    let mut dots = HashSet::new();
+    let count = size.unwrap_or(800).max(code.len());
+    for i in 0..count {
+        let (mut x, mut y) = code.get(i).copied().unwrap_or_else(|| *rng.choose(&code));
+        for &(axis, line) in &folds {
+            if rng.chance(0.5) {
+                match axis {
+                    'x' => x = 2 * line - x,
+                    _ => y = 2 * line - y,
+                }
+            }
+        }
+        dots.insert((x, y));
+    }
+
+    let mut dots: Vec<(usize, usize)> = dots.into_iter().collect();
+    dots.sort();
+    rng.shuffle(&mut dots);
+    let dots = dots.into_iter().map(|(x, y)| format!("{},{}", x, y));
+    let folds = folds
+        .iter()
+        .rev()
+        .map(|(axis, line)| format!("fold along {}={}", axis, line));
+    Generated::new(lines(dots) + "\n" + &lines(folds))
+}
@@ -0,0 +1,192 @@
+mod generate;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use geometry::{Bounds2, Point2};
+
+fn part1(initial_coords: &[Point2], instructions: &[Instruction]) -> usize {
+    let new_coords = fold_along(initial_coords, instructions[0]);
+    new_coords.len()
+}
+
+fn part2(initial_coords: &[Point2], instructions: &[Instruction]) -> String {
+    let mut new_coords = initial_coords.to_vec();
+    animate_paper(&new_coords, "Unfolded".to_string());
+    for (i, instruction) in instructions.iter().enumerate() {
+        new_coords = fold_along(&new_coords, *instruction);
+        let caption = format!(
+            "Fold {} of {}: along {}={}",
+            i + 1,
+            instructions.len(),
+            instruction.dir,
+            instruction.pos
+        );
+        animate_paper(&new_coords, caption);
+    }
+
+    render_coords(&new_coords)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub dir: char,
+    pub pos: i64,
+}
+
+impl Instruction {
+    fn new(dir: char, pos: i64) -> Self {
+        Instruction { dir, pos }
+    }
+}
+
+fn fill_initial_coords(lines: &[&str]) -> Result<Vec<Point2>, ParseError> {
+    let mut coords = Vec::new();
+    for line in lines {
+        if lines.is_empty() || !line.contains(',') {
+            break;
+        }
+        let (x, y) = parse::split_once(line, ",")?;
+        coords.push(Point2::new(parse::number(x)?, parse::number(y)?));
+    }
+    Ok(coords)
+}
+
+fn get_fold_instructions(lines: &[&str]) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    for line in lines {
+        if line.contains(',') || line.trim().is_empty() {
+            continue;
+        }
+        let fold = match line.strip_prefix("fold along ") {
+            Some(fold) => fold,
+            None => return Err(ParseError::new(line.trim(), "'fold along'")),
+        };
+        let (dir, pos) = parse::split_once(fold, "=")?;
+        let dir = match dir.trim() {
+            "x" => 'x',
+            "y" => 'y',
+            other => return Err(ParseError::new(other, "'x' or 'y'")),
+        };
+        instructions.push(Instruction::new(dir, parse::number(pos)?));
+    }
+    Ok(instructions)
+}
+
+fn fold_along(coords: &[Point2], instruction: Instruction) -> Vec<Point2> {
+    let mut new_coords = Vec::new();
+    for coord in coords {
+        match instruction.dir {
+            'x' => {
+                if coord.x() > instruction.pos {
+                    new_coords.push(Point2::new(2 * instruction.pos - coord.x(), coord.y()));
+                } else {
+                    new_coords.push(*coord);
+                }
+            }
+            'y' => {
+                if coord.y() > instruction.pos {
+                    new_coords.push(Point2::new(coord.x(), 2 * instruction.pos - coord.y()));
+                } else {
+                    new_coords.push(*coord);
+                }
+            }
+            _ => panic!("Unknown direction {}", instruction.dir),
+        }
+    }
+
+    new_coords.sort();
+    new_coords.dedup();
+
+    new_coords
+}
+
+fn animate_paper(coords: &[Point2], caption: String) {
+    animate::frame(|| {
+        // render_coords needs them sorted, which the dots before any folds aren't
+        let mut coords = coords.to_vec();
+        coords.sort();
+        Frame::from_text(caption, &render_coords(&coords), |c| {
+            (c == '#').then_some(Color::Cyan)
+        })
+    });
+}
+
+fn render_coords(coords: &[Point2]) -> String {
+    let bounds = match Bounds2::from_points(coords.iter().copied()) {
+        Some(bounds) => bounds,
+        None => return String::new(),
+    };
+
+    let mut picture = String::new();
+    for y in bounds.min.y()..=bounds.max.y() {
+        if y > bounds.min.y() {
+            picture.push('\n');
+        }
+        for x in bounds.min.x()..=bounds.max.x() {
+            let coord = Point2::new(x, y);
+            if coords.binary_search(&coord).is_ok() {
+                picture.push('#');
+            } else {
+                picture.push('.');
+            }
+        }
+    }
+    picture
+}
+
+fn parse(input: &str) -> Result<(Vec<Point2>, Vec<Instruction>), ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let initial_coords = fill_initial_coords(&lines)?;
+    let instructions = get_fold_instructions(&lines)?;
+    if instructions.is_empty() {
+        return Err(ParseError::after(input.trim_end(), "a fold instruction"));
+    }
+    Ok((initial_coords, instructions))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Vec<Point2>, Vec<Instruction>);
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((initial_coords, instructions): &Self::Input, _config: &Self::Config) -> Answer {
+        part1(initial_coords, instructions).into()
+    }
+
+    fn part2((initial_coords, instructions): &Self::Input, _config: &Self::Config) -> Answer {
+        Answer::Grid(part2(initial_coords, instructions))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day13>(EXAMPLE, 1), Ok(Answer::Int(17)));
+    }
+
+    #[test]
+    fn part2_example() {
+        let square = "#####\n#...#\n#...#\n#...#\n#####";
+        assert_eq!(
+            solve::<Day13>(EXAMPLE, 2),
+            Ok(Answer::Grid(square.to_string()))
+        );
+    }
+}
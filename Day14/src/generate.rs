@@ -0,0 +1,20 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the length of the polymer template. Ten letters are used, with an insertion rule
+// for every pair of them.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut alphabet: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut alphabet);
+    alphabet.truncate(10);
+
+    let template: String = (0..size.unwrap_or(20).max(2))
+        .map(|_| *rng.choose(&alphabet))
+        .collect();
+    let mut input = template + "\n\n";
+    for &first in &alphabet {
+        for &second in &alphabet {
+            input += &format!("{}{} -> {}\n", first, second, rng.choose(&alphabet));
+        }
+    }
+    Generated::new(input)
+}
@@ -0,0 +1,275 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+aoc_common::config! {
+    pub struct Day14Config {
+        // Insertion steps to run for each part
+        part1_steps: usize = 10,
+        part2_steps: usize = 40,
+    }
+    check = check_config;
+}
+
+// The polymer doubles in length every step, so its letter counts overflow not far past this.
+// get_letter_count also recurses once per step and would overflow the stack long before a
+// huge step count finished.
+const MAX_STEPS: usize = 60;
+
+fn check_config(config: &Day14Config) -> Result<(), String> {
+    for (name, steps) in [
+        ("part1_steps", config.part1_steps),
+        ("part2_steps", config.part2_steps),
+    ] {
+        if steps > MAX_STEPS {
+            return Err(format!("{} must be at most {}", name, MAX_STEPS));
+        }
+    }
+    Ok(())
+}
+
+fn do_steps(template: &str, rules: &mut HashMap<String, Rule>, num_steps: usize) -> usize {
+    let mut letter_counts = [0; 26];
+    letter_counts[template.chars().nth(template.len() - 1).unwrap() as usize - 'A' as usize] += 1;
+    for i in 0..template.len() - 1 {
+        let key = &template[i..i + 2];
+        let cur_letter_counts = get_letter_count(rules, key, num_steps);
+        letter_counts = append_letter_counts(&letter_counts, &cur_letter_counts);
+    }
+
+    letter_counts.iter().max().unwrap() - letter_counts.iter().filter(|&x| *x > 0).min().unwrap()
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    input: String,
+    output: String,
+    letter_count_cache: Vec<[usize; 26]>,
+}
+
+impl Rule {
+    fn new(input: &str, output: &str) -> Rule {
+        let mut rule = Rule {
+            input: input.to_string(),
+            output: output.to_string(),
+            letter_count_cache: Vec::new(),
+        };
+
+        let mut letter_counts = [0; 26];
+        for c in input[0..1].chars() {
+            letter_counts[c as usize - 'A' as usize] += 1;
+        }
+        rule.letter_count_cache.push(letter_counts);
+
+        rule
+    }
+}
+
+fn append_letter_counts(a: &[usize; 26], b: &[usize; 26]) -> [usize; 26] {
+    let mut result = [0; 26];
+    for i in 0..26 {
+        result[i] = a[i] + b[i];
+    }
+    result
+}
+
+fn get_letter_count(rules: &mut HashMap<String, Rule>, input: &str, index: usize) -> [usize; 26] {
+    let (left_rule, right_rule, letter_count_cache_size) = {
+        let rule = rules.get(input).unwrap();
+        let left_rule = rule.input[0..1].to_string() + &rule.output[0..1];
+        let right_rule = rule.output[0..1].to_string() + &rule.input[1..2];
+        let letter_count_cache_size = rule.letter_count_cache.len();
+        (left_rule, right_rule, letter_count_cache_size)
+    };
+
+    for cur_index in letter_count_cache_size..=index {
+        let left_letter_count = get_letter_count(rules, &left_rule, cur_index - 1);
+        let right_letter_count = get_letter_count(rules, &right_rule, cur_index - 1);
+        let letter_counts = append_letter_counts(&left_letter_count, &right_letter_count);
+
+        rules
+            .get_mut(input)
+            .unwrap()
+            .letter_count_cache
+            .push(letter_counts);
+    }
+
+    rules.get_mut(input).unwrap().letter_count_cache[index]
+}
+
+fn check_letters(text: &str, len: Option<usize>, expected: &str) -> Result<(), ParseError> {
+    if let Some(i) = text.find(|c: char| !c.is_ascii_uppercase()) {
+        return Err(ParseError::new(
+            parse::char_at(text, i),
+            "an uppercase letter",
+        ));
+    }
+    match len {
+        Some(len) if text.len() != len => Err(ParseError::new(text, expected)),
+        _ if text.is_empty() => Err(ParseError::new(text, expected)),
+        _ => Ok(()),
+    }
+}
+
+fn parse(input: &str) -> Result<(String, HashMap<String, Rule>), ParseError> {
+    let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+
+    let template = lines.first().copied().unwrap_or(input);
+    check_letters(template, None, "a polymer template")?;
+
+    let mut rules: HashMap<String, Rule> = HashMap::new();
+    for line in lines.iter().skip(1).filter(|line| !line.is_empty()) {
+        let (pair, insert) = parse::split_once(line, " -> ")?;
+        check_letters(pair, Some(2), "a pair of letters")?;
+        check_letters(insert, Some(1), "a single letter")?;
+        rules.insert(pair.to_string(), Rule::new(pair, insert));
+    }
+    check_rules_cover(template, &lines, &rules)?;
+
+    Ok((template.to_string(), rules))
+}
+
+// Every pair the template can grow into needs a rule, or the counts can't be looked up. A
+// missing pair is reported at the template or at the rule line that first makes it, the last
+// one for its pair since that's the rule kept.
+fn check_rules_cover<'a>(
+    template: &'a str,
+    lines: &[&'a str],
+    rules: &HashMap<String, Rule>,
+) -> Result<(), ParseError> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<(String, &str)> = (0..template.len().saturating_sub(1))
+        .map(|i| (template[i..i + 2].to_string(), &template[i..i + 2]))
+        .collect();
+    while let Some((pair, made_by)) = queue.pop_front() {
+        if !seen.insert(pair.clone()) {
+            continue;
+        }
+        let rule = rules
+            .get(&pair)
+            .ok_or_else(|| ParseError::new(made_by, format!("a rule for {}", pair)))?;
+        let line = lines
+            .iter()
+            .skip(1)
+            .rev()
+            .find(|line| line.starts_with(&pair))
+            .copied()
+            .unwrap_or(made_by);
+        queue.push_back((rule.input[0..1].to_string() + &rule.output, line));
+        queue.push_back((rule.output.clone() + &rule.input[1..2], line));
+    }
+    Ok(())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (String, HashMap<String, Rule>);
+    type Config = Day14Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((template, rules): &Self::Input, config: &Self::Config) -> Answer {
+        do_steps(template, &mut rules.clone(), config.part1_steps).into()
+    }
+
+    fn part2((template, rules): &Self::Input, config: &Self::Config) -> Answer {
+        do_steps(template, &mut rules.clone(), config.part2_steps).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day14>(EXAMPLE, 1), Ok(Answer::Int(1588)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day14>(EXAMPLE, 2), Ok(Answer::Int(2188189693529)));
+    }
+
+    #[test]
+    fn rejects_missing_rules() {
+        let error = solve::<Day14>("AB\n\nAB -> C\n", 1).unwrap_err();
+        assert_eq!(error.expected, "a rule for AC");
+        assert_eq!(error.found, "'AB -> C'");
+        let error = solve::<Day14>("ABA\n\nAB -> A\nAA -> A\n", 1).unwrap_err();
+        assert_eq!(error.expected, "a rule for BA");
+        assert_eq!(error.found, "'BA'");
+        assert_eq!(solve::<Day14>("A\n", 1), Ok(Answer::Int(0)));
+    }
+
+    #[test]
+    fn limits_steps() {
+        let config = Day14Config {
+            part2_steps: 1000000,
+            ..Day14Config::default()
+        };
+        assert_eq!(
+            check_config(&config),
+            Err("part2_steps must be at most 60".to_string())
+        );
+    }
+
+    const LETTERS: [char; 4] = ['B', 'C', 'H', 'N'];
+
+    // Builds the whole polymer a step at a time instead of caching counts per pair
+    fn naive_steps(template: &str, rules: &HashMap<(char, char), char>, num_steps: usize) -> usize {
+        let mut polymer: Vec<char> = template.chars().collect();
+        for _ in 0..num_steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                next.push(rules[&(pair[0], pair[1])]);
+                next.push(pair[1]);
+            }
+            polymer = next;
+        }
+
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for c in polymer {
+            *counts.entry(c).or_default() += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    proptest! {
+        // Every pair of the four letters has a rule, so any template can be expanded
+        #[test]
+        fn matches_expanded_polymer(
+            template in "[BCHN]{1,8}",
+            outputs in prop::collection::vec(prop::sample::select(LETTERS.to_vec()), 16),
+            num_steps in 0usize..8,
+        ) {
+            let mut rules = HashMap::new();
+            let mut input = template.clone() + "\n\n";
+            for (i, &output) in outputs.iter().enumerate() {
+                let pair = (LETTERS[i / 4], LETTERS[i % 4]);
+                rules.insert(pair, output);
+                input += &format!("{}{} -> {}\n", pair.0, pair.1, output);
+            }
+
+            let (template, mut parsed_rules) = parse(&input).unwrap();
+            prop_assert_eq!(
+                do_steps(&template, &mut parsed_rules, num_steps),
+                naive_steps(&template, &rules, num_steps)
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the side of the square risk map
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let side = size.unwrap_or(100);
+    let mut input = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            input += &rng.range(1..=9).to_string();
+        }
+        input.push('\n');
+    }
+    Generated::new(input)
+}
@@ -0,0 +1,146 @@
+mod generate;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use search::astar;
+
+pub type CostMap = Grid<usize>;
+
+// Risk levels with the positions the search has expanded in blue and the path found in red
+fn animate_search(costs: &CostMap, expanded: &Grid<bool>, path: &[Pos], caption: String) {
+    animate::frame(|| {
+        let mut colors = expanded.map(|&expanded| expanded.then_some(Color::Blue));
+        for &pos in path {
+            colors[pos] = Some(Color::Red);
+        }
+        let rows = costs
+            .rows()
+            .zip(colors.rows())
+            .map(|(costs, colors)| {
+                let cells = costs.iter().zip(colors).map(|(&cost, &color)| {
+                    (char::from_digit(cost as u32, 10).unwrap_or('?'), color)
+                });
+                cells.collect()
+            })
+            .collect();
+        Frame::new(caption, rows)
+    });
+}
+
+fn find_shortest_cost(costs: &CostMap) -> usize {
+    let goal = (costs.width() - 1, costs.height() - 1);
+
+    // Where the search has been, kept only while animating. A frame is drawn every hundredth
+    // of the map so large maps take as long to watch as small ones.
+    let mut expanded = animate::enabled().then(|| costs.map(|_| false));
+    let frame_every = (costs.len() / 100).max(1);
+    let mut expansions = 0usize;
+
+    // Every step costs at least 1, so the distance left to the goal never overestimates
+    let distance_to_goal = |&(x, y): &(usize, usize)| (goal.0 - x) + (goal.1 - y);
+    let (path, cost) = astar(
+        (0, 0),
+        |&pos| {
+            if let Some(expanded) = &mut expanded {
+                expanded[pos] = true;
+                expansions += 1;
+                if expansions.is_multiple_of(frame_every) {
+                    let caption = format!("{} positions expanded", expansions);
+                    animate_search(costs, expanded, &[], caption);
+                }
+            }
+            costs.neighbors4(pos).map(|next| (next, costs[next]))
+        },
+        distance_to_goal,
+        |&pos| pos == goal,
+    )
+    .unwrap();
+
+    if let Some(expanded) = &expanded {
+        let caption = format!("Lowest total risk {}", cost);
+        animate_search(costs, expanded, &path, caption);
+    }
+    cost
+}
+
+fn part1(costs: &CostMap) -> usize {
+    find_shortest_cost(costs)
+}
+
+fn part2(costs: &CostMap) -> usize {
+    let size_x = costs.width();
+    let size_y = costs.height();
+
+    let mut full_costs = Grid::new(size_x * 5, size_y * 5, 0);
+    for (p, &cost) in costs.iter() {
+        for i in 0..5 {
+            for j in 0..5 {
+                let dist = i + j;
+                let new_cost = (((cost - 1) + dist) % 9) + 1;
+                full_costs[(p.0 + i * size_x, p.1 + j * size_y)] = new_cost;
+            }
+        }
+    }
+
+    find_shortest_cost(&full_costs)
+}
+
+// Risk levels run from 1 to 9. A 0 would break the A* heuristic, which relies on every step
+// costing at least 1.
+fn parse_costs(input: &str) -> Result<CostMap, ParseError> {
+    Grid::parse_chars(input, "a digit from 1 to 9", |c| match c.to_digit(10) {
+        Some(0) | None => None,
+        Some(d) => Some(d as usize),
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = CostMap;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse_costs(input)
+    }
+
+    fn part1(costs: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(costs).into()
+    }
+
+    fn part2(costs: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(costs).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day15>(EXAMPLE, 1), Ok(Answer::Int(40)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day15>(EXAMPLE, 2), Ok(Answer::Int(315)));
+    }
+
+    #[test]
+    fn rejects_zero_risk() {
+        let error = solve::<Day15>("19\n10\n", 2).unwrap_err();
+        assert_eq!(error.expected, "a digit from 1 to 9");
+        assert_eq!(error.found, "'0'");
+    }
+}
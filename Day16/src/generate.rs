@@ -0,0 +1,131 @@
+use aoc_common::generate::{Generated, Rng};
+
+const SUM: u8 = 0;
+const PRODUCT: u8 = 1;
+const MINIMUM: u8 = 2;
+const MAXIMUM: u8 = 3;
+const LITERAL: u8 = 4;
+
+struct Writer {
+    bits: Vec<bool>,
+}
+
+impl Writer {
+    fn push(&mut self, value: usize, width: usize) {
+        for bit in (0..width).rev() {
+            self.bits.push((value >> bit) & 1 == 1);
+        }
+    }
+}
+
+// A packet's bits along with its version sum and value
+struct Packet {
+    bits: Vec<bool>,
+    versions: usize,
+    value: usize,
+}
+
+fn literal(rng: &mut Rng) -> Packet {
+    let version = rng.below(8);
+    let value = if rng.chance(0.1) {
+        rng.below(1 << 32)
+    } else {
+        rng.below(1000)
+    };
+
+    let mut writer = Writer { bits: Vec::new() };
+    writer.push(version, 3);
+    writer.push(LITERAL as usize, 3);
+    let groups = (usize::BITS - value.leading_zeros()).div_ceil(4).max(1) as usize;
+    for group in (0..groups).rev() {
+        writer.push((group > 0) as usize, 1);
+        writer.push((value >> (group * 4)) & 0xf, 4);
+    }
+
+    Packet {
+        bits: writer.bits,
+        versions: version,
+        value,
+    }
+}
+
+// Builds a random packet tree of at most `budget` packets. Values are worked out as the tree
+// is built, and a sum or product that would overflow becomes a maximum instead.
+fn packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> Packet {
+    *budget = budget.saturating_sub(1);
+    if *budget == 0 || depth >= 8 || (depth > 0 && rng.chance(0.35)) {
+        return literal(rng);
+    }
+
+    let mut type_id = [SUM, PRODUCT, MINIMUM, MAXIMUM, 5, 6, 7][rng.below(7)];
+    let count = if type_id >= 5 {
+        2
+    } else {
+        rng.range(1..=4) as usize
+    };
+    let children: Vec<Packet> = (0..count).map(|_| packet(rng, budget, depth + 1)).collect();
+
+    let values = children.iter().map(|child| child.value);
+    let checked = match type_id {
+        SUM => values.clone().try_fold(0usize, |a, b| a.checked_add(b)),
+        PRODUCT => values.clone().try_fold(1usize, |a, b| a.checked_mul(b)),
+        _ => Some(0),
+    };
+    if checked.is_none() {
+        type_id = MAXIMUM;
+    }
+    let value = match type_id {
+        SUM | PRODUCT => checked.unwrap(),
+        MINIMUM => values.min().unwrap(),
+        MAXIMUM => values.max().unwrap(),
+        5 => (children[0].value > children[1].value) as usize,
+        6 => (children[0].value < children[1].value) as usize,
+        _ => (children[0].value == children[1].value) as usize,
+    };
+
+    let version = rng.below(8);
+    let mut writer = Writer { bits: Vec::new() };
+    writer.push(version, 3);
+    writer.push(type_id as usize, 3);
+    let length: usize = children.iter().map(|child| child.bits.len()).sum();
+    if length < 1 << 15 && rng.chance(0.5) {
+        writer.push(0, 1);
+        writer.push(length, 15);
+    } else {
+        writer.push(1, 1);
+        writer.push(children.len(), 11);
+    }
+    for child in &children {
+        writer.bits.extend(&child.bits);
+    }
+
+    Packet {
+        bits: writer.bits,
+        versions: version + children.iter().map(|child| child.versions).sum::<usize>(),
+        value,
+    }
+}
+
+// `size` is the most packets the transmission holds. Both answers are known, since they are
+// worked out while the packets are built.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut budget = size.unwrap_or(60).max(1);
+    let mut packet = packet(rng, &mut budget, 0);
+
+    // Padded with zeros to whole bytes, as the transmission is hex encoded bytes
+    while !packet.bits.len().is_multiple_of(8) {
+        packet.bits.push(false);
+    }
+    let hex: String = packet
+        .bits
+        .chunks(4)
+        .map(|nibble| {
+            let digit = nibble.iter().fold(0, |acc, &bit| acc << 1 | bit as u32);
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+
+    Generated::new(hex + "\n")
+        .with_answer(1, packet.versions)
+        .with_answer(2, packet.value)
+}
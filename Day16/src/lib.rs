@@ -0,0 +1,340 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use bitvec::prelude::*;
+
+type BS = BitSlice<Msb0, u8>;
+
+// Deeper packets are refused rather than risking the stack, since each level is a recursive
+// call. Real transmissions are a handful of levels deep.
+const MAX_DEPTH: usize = 256;
+
+fn part1(packet: &Packet) -> usize {
+    packet.total_version()
+}
+
+fn part2(packet: &Packet) -> usize {
+    packet.calc_value().expect("Checked when parsed")
+}
+
+#[derive(Debug, Clone)]
+pub struct Packet {
+    v: u8,
+    t: u8,
+    literal: usize,
+    sub_packets: Vec<Packet>,
+}
+
+impl Packet {
+    fn new(reader: &mut DataReader, depth: usize) -> Result<Packet, ParseError> {
+        let start = reader.span();
+        if depth > MAX_DEPTH {
+            return Err(ParseError::new(start, "packets nested at most 256 deep"));
+        }
+        let v = reader.read_data_u8(3)?;
+        let t = reader.read_data_u8(3)?;
+        let literal = if t == 4 {
+            let mut literal: usize = 0;
+            loop {
+                let has_more = reader.read_data_u8(1)?;
+                let segment = reader.read_data_usize(4)?;
+                if literal >> (usize::BITS - 4) != 0 {
+                    return Err(ParseError::new(start, "a literal that fits in 64 bits"));
+                }
+                literal = (literal << 4) | segment;
+
+                if has_more == 0 {
+                    break;
+                }
+            }
+
+            literal
+        } else {
+            0usize
+        };
+
+        let mut sub_packets = Vec::new();
+        if t != 4 {
+            let length_type_id = reader.read_data_u8(1)?;
+            if length_type_id == 0 {
+                // 15 bit length - number of bits used by sub-packets
+                let length = reader.read_data_usize(15)?;
+                let starting_offset = reader.offset;
+                while reader.offset - starting_offset < length {
+                    let packet = Packet::new(reader, depth + 1)?;
+                    sub_packets.push(packet);
+                }
+                if reader.offset - starting_offset != length {
+                    return Err(ParseError::new(start, "sub-packets that fill their length"));
+                }
+            } else {
+                // 11 bit length - number of sub-packets
+                let length = reader.read_data_usize(11)?;
+                for _ in 0..length {
+                    let packet = Packet::new(reader, depth + 1)?;
+                    sub_packets.push(packet);
+                }
+            }
+
+            // Comparison operators always compare exactly two values
+            if t >= 5 && sub_packets.len() != 2 {
+                return Err(ParseError::new(start, "a comparison with two sub-packets"));
+            }
+        }
+
+        Ok(Packet {
+            v,
+            t,
+            literal,
+            sub_packets,
+        })
+    }
+
+    fn total_version(&self) -> usize {
+        self.v as usize
+            + self
+                .sub_packets
+                .iter()
+                .map(|p| p.total_version())
+                .sum::<usize>()
+    }
+
+    // None if a sum or product overflows along the way. Comparisons were checked to have two
+    // sub-packets when parsed.
+    fn calc_value(&self) -> Option<usize> {
+        let mut values = self.sub_packets.iter().map(|p| p.calc_value());
+        match self.t {
+            0 => values.try_fold(0usize, |acc, value| acc.checked_add(value?)),
+            1 => values.try_fold(1usize, |acc, value| acc.checked_mul(value?)),
+            2 => values
+                .collect::<Option<Vec<usize>>>()
+                .map(|v| v.into_iter().min().unwrap_or(0)),
+            3 => values
+                .collect::<Option<Vec<usize>>>()
+                .map(|v| v.into_iter().max().unwrap_or(0)),
+            4 => Some(self.literal),
+            _ => {
+                let [a, b] = &self.sub_packets[..] else {
+                    unreachable!("Comparison without two sub-packets");
+                };
+                let (a, b) = (a.calc_value()?, b.calc_value()?);
+                Some(match self.t {
+                    5 => (a > b) as usize,
+                    6 => (a < b) as usize,
+                    _ => (a == b) as usize,
+                })
+            }
+        }
+    }
+}
+
+struct DataReader<'a> {
+    data: &'a BS,
+    // The hex digits the data was decoded from, for pointing errors at
+    hex: &'a str,
+    offset: usize,
+}
+
+impl<'a> DataReader<'a> {
+    fn new(data: &'a BS, hex: &'a str) -> DataReader<'a> {
+        DataReader {
+            data,
+            hex,
+            offset: 0,
+        }
+    }
+
+    // The hex digit holding the next bit to be read
+    fn span(&self) -> &'a str {
+        let index = (self.offset / 4).min(self.hex.len());
+        parse::char_at(self.hex, index)
+    }
+
+    fn read_bits(&mut self, size: usize) -> Result<&'a BS, ParseError> {
+        match self.data.get(self.offset..self.offset + size) {
+            Some(bits) => {
+                self.offset += size;
+                Ok(bits)
+            }
+            None => Err(ParseError::new(self.span(), "the rest of the packet")),
+        }
+    }
+
+    fn read_data_u8(&mut self, size: usize) -> Result<u8, ParseError> {
+        Ok(to_int_u8(self.read_bits(size)?))
+    }
+
+    fn read_data_usize(&mut self, size: usize) -> Result<usize, ParseError> {
+        Ok(to_int_usize(self.read_bits(size)?))
+    }
+}
+
+fn to_int_u8(bits: &BS) -> u8 {
+    bits.iter().fold(0, |acc, x| acc * 2 + *x as u8)
+}
+
+fn to_int_usize(bits: &BS) -> usize {
+    bits.iter().fold(0, |acc, x| acc * 2 + *x as usize)
+}
+
+fn parse(input: &str) -> Result<Packet, ParseError> {
+    let hex_text = input.trim();
+    if let Some(i) = hex_text.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(ParseError::new(parse::char_at(hex_text, i), "a hex digit"));
+    }
+    let data = hex::decode(hex_text)
+        .map_err(|_| ParseError::after(hex_text, "an even number of hex digits"))?;
+
+    let mut reader = DataReader::new(data.view_bits::<Msb0>(), hex_text);
+    let packet = Packet::new(&mut reader, 0)?;
+    if packet.calc_value().is_none() {
+        return Err(ParseError::new(
+            hex_text,
+            "a transmission whose value fits in 64 bits",
+        ));
+    }
+    Ok(packet)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] =
+        aoc_common::examples!("example1.txt", "example2.txt", "example3.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(packet: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(packet).into()
+    }
+
+    fn part2(packet: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(packet).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE1: &str = include_str!("../example1.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(solve::<Day16>(EXAMPLE1, 1), Ok(Answer::Int(6)));
+        assert_eq!(solve::<Day16>(EXAMPLE2, 1), Ok(Answer::Int(16)));
+        assert_eq!(solve::<Day16>(EXAMPLE3, 1), Ok(Answer::Int(12)));
+        assert_eq!(
+            solve::<Day16>("C0015000016115A2E0802F182340", 1),
+            Ok(Answer::Int(23))
+        );
+        assert_eq!(
+            solve::<Day16>("A0016C880162017C3686B18A3D4780", 1),
+            Ok(Answer::Int(31))
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        // The literal packet in example1
+        assert_eq!(solve::<Day16>(EXAMPLE1, 2), Ok(Answer::Int(2021)));
+
+        let examples = [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ];
+        for (packet, value) in examples {
+            assert_eq!(
+                solve::<Day16>(packet, 2),
+                Ok(Answer::Int(value)),
+                "{}",
+                packet
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..20 {
+            let generated = Day16::generate(&mut Rng::new(seed), Some(30));
+            for (part, answer) in &generated.answers {
+                assert_eq!(solve::<Day16>(&generated.input, *part).as_ref(), Ok(answer));
+            }
+        }
+    }
+
+    // Packs a string of bits into hex, padded with zeros to whole bytes
+    fn to_hex(bits: &str) -> String {
+        let mut bits = bits.to_string();
+        while !bits.len().is_multiple_of(8) {
+            bits.push('0');
+        }
+        bits.as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                let digit = nibble
+                    .iter()
+                    .fold(0, |acc, &bit| acc << 1 | (bit - b'0') as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+
+    fn expected_error(bits: &str) -> String {
+        solve::<Day16>(&to_hex(bits), 1).unwrap_err().expected
+    }
+
+    #[test]
+    fn rejects_malformed_transmissions() {
+        assert_eq!(
+            solve::<Day16>("D2FE", 1).unwrap_err().expected,
+            "the rest of the packet"
+        );
+
+        let long_literal = "000100".to_string() + &"11111".repeat(16) + "01111";
+        assert_eq!(
+            expected_error(&long_literal),
+            "a literal that fits in 64 bits"
+        );
+
+        let big_literal = "000100".to_string() + "10001" + &"10000".repeat(9) + "00000";
+        let product = "000001100000000010".to_string() + &big_literal + &big_literal;
+        assert_eq!(
+            expected_error(&product),
+            "a transmission whose value fits in 64 bits"
+        );
+
+        // Greater-than packets counting one and three literal sub-packets
+        let literal = "00010000001";
+        let one = "000101100000000001".to_string() + literal;
+        let three = "000101100000000011".to_string() + &literal.repeat(3);
+        for comparison in [one, three] {
+            assert_eq!(
+                expected_error(&comparison),
+                "a comparison with two sub-packets"
+            );
+        }
+
+        let nested = "0000001".to_string() + "00000000001";
+        let deep = nested.repeat(300) + "00010000001";
+        assert_eq!(expected_error(&deep), "packets nested at most 256 deep");
+    }
+}
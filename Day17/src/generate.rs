@@ -0,0 +1,12 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is roughly how far away the target area is. The solver only tries upward
+// velocities below 1000, so anything past that is clamped.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let distance = size.unwrap_or(150).clamp(10, 900) as i64;
+    let x0 = rng.range(distance..=distance * 4 / 3);
+    let x1 = x0 + rng.range(distance / 10..=distance / 3);
+    let y0 = -rng.range(distance * 2 / 3..=distance);
+    let y1 = (y0 + rng.range(distance / 10..=distance / 3)).min(-1);
+    Generated::new(format!("target area: x={}..{}, y={}..{}\n", x0, x1, y0, y1))
+}
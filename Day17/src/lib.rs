@@ -0,0 +1,141 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parallel, parse, Answer, ParseError, Solution};
+use regex::Regex;
+
+// Every starting dx is swept on its own, so they can be spread across threads
+fn sweep<R: Send>(dxs: std::ops::Range<i64>, f: impl Fn(i64) -> R + Sync + Send) -> Vec<R> {
+    let dxs: Vec<i64> = dxs.collect();
+    parallel::map(&dxs, |&dx| f(dx))
+}
+
+fn part1(target_area: &TargetArea) -> i64 {
+    let highest_per_dx = sweep(0..target_area.x1, |dx| {
+        let mut highest_point_reached = 0;
+        for dy in 0..1000 {
+            let mut x = 0;
+            let mut y = 0;
+            let mut dx = dx;
+            let mut dy = dy;
+            let mut cur_highest_point_reached = 0;
+            while !target_area.is_beyond(x, y) {
+                cur_highest_point_reached = i64::max(cur_highest_point_reached, y);
+                if target_area.contains(x, y) {
+                    highest_point_reached =
+                        i64::max(highest_point_reached, cur_highest_point_reached);
+                    break;
+                }
+
+                x += dx;
+                y += dy;
+                dx -= i64::signum(dx);
+                dy -= 1;
+            }
+        }
+        highest_point_reached
+    });
+
+    highest_per_dx.into_iter().max().unwrap_or(0)
+}
+
+fn part2(target_area: &TargetArea) -> usize {
+    let velocities_per_dx = sweep(0..target_area.x1 + 1, |dx| {
+        let mut num_velocities = 0;
+        for dy in target_area.y0..1000 {
+            let mut x = 0;
+            let mut y = 0;
+            let mut dx = dx;
+            let mut dy = dy;
+            while !target_area.is_beyond(x, y) {
+                if target_area.contains(x, y) {
+                    num_velocities += 1;
+                    break;
+                }
+
+                x += dx;
+                y += dy;
+                dx -= i64::signum(dx);
+                dy -= 1;
+            }
+        }
+        num_velocities
+    });
+
+    velocities_per_dx.into_iter().sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TargetArea {
+    x0: i64,
+    x1: i64,
+    y0: i64,
+    y1: i64,
+}
+
+impl TargetArea {
+    fn new(input: &str) -> Result<TargetArea, ParseError> {
+        let input = input.trim();
+        let re = Regex::new(r"^target area: x=(-?\d+)\.\.(-?\d+), y=(-?\d+)\.\.(-?\d+)$").unwrap();
+        let cap = re
+            .captures(input)
+            .ok_or_else(|| ParseError::new(input, "'target area: x=A..B, y=C..D'"))?;
+        let number = |i: usize| parse::number::<i64>(cap.get(i).unwrap().as_str());
+        Ok(TargetArea {
+            x0: number(1)?,
+            x1: number(2)?,
+            y0: number(3)?,
+            y1: number(4)?,
+        })
+    }
+
+    fn contains(&self, x: i64, y: i64) -> bool {
+        x >= self.x0 && x <= self.x1 && y >= self.y0 && y <= self.y1
+    }
+
+    fn is_beyond(&self, x: i64, y: i64) -> bool {
+        x > self.x1 || y < self.y0
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = TargetArea;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        TargetArea::new(input)
+    }
+
+    fn part1(target_area: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(target_area).into()
+    }
+
+    fn part2(target_area: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(target_area).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day17>(EXAMPLE, 1), Ok(Answer::Int(45)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day17>(EXAMPLE, 2), Ok(Answer::Int(112)));
+    }
+}
@@ -0,0 +1,15 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+// Pairs are nested at most four deep, like a reduced number
+fn number(rng: &mut Rng, depth: usize) -> String {
+    if depth > 0 && (depth == 4 || rng.chance(0.3)) {
+        return rng.range(0..=9).to_string();
+    }
+    format!("[{},{}]", number(rng, depth + 1), number(rng, depth + 1))
+}
+
+// `size` is the number of snailfish numbers
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let numbers = (0..size.unwrap_or(100).max(1)).map(|_| number(rng, 0));
+    Generated::new(lines(numbers))
+}
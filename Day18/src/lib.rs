@@ -0,0 +1,422 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parallel, parse, Answer, ParseError, Solution};
+use itertools::Itertools;
+
+fn part1(number_trees: &[NumberTree]) -> i64 {
+    let (first, rest) = match number_trees.split_first() {
+        Some(split) => split,
+        None => return 0,
+    };
+    let mut accum_tree: NumberTree = first.clone();
+    for tree in rest {
+        accum_tree = accum_tree.add(tree);
+    }
+
+    accum_tree.magnitude()
+}
+
+fn part2(number_trees: &[NumberTree]) -> i64 {
+    let tree_pairs: Vec<Vec<usize>> = (0..number_trees.len()).permutations(2).collect();
+    let magnitudes = parallel::map(&tree_pairs, |tree_pair| {
+        let tree = number_trees[tree_pair[0]].add(&number_trees[tree_pair[1]]);
+        tree.magnitude()
+    });
+
+    magnitudes.into_iter().max().unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Literal(i64),
+    Pair(NumberPair),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct NumberPair {
+    x: usize,
+    y: usize,
+}
+
+impl NumberPair {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // Numbers in the homework are already reduced, which reduce() relies on: pairs are at
+    // most four deep and regular numbers are single digits
+    fn parse(
+        reader: &mut StringReader,
+        value_array: &mut Vec<Value>,
+        depth: usize,
+    ) -> Result<usize, ParseError> {
+        if depth > 4 {
+            return Err(ParseError::new(
+                reader.span(),
+                "a pair nested at most 4 deep",
+            ));
+        }
+        reader.expect_char('[')?;
+        let x = Self::read_value(reader, value_array, depth)?;
+        reader.expect_char(',')?;
+        let y = Self::read_value(reader, value_array, depth)?;
+        reader.expect_char(']')?;
+
+        let parent_index = value_array.len();
+        let parent: Value = Value::Pair(NumberPair::new(x, y));
+        value_array.push(parent);
+
+        Ok(parent_index)
+    }
+
+    fn read_value(
+        reader: &mut StringReader,
+        value_array: &mut Vec<Value>,
+        depth: usize,
+    ) -> Result<usize, ParseError> {
+        if reader.peek_char() == Some('[') {
+            NumberPair::parse(reader, value_array, depth + 1)
+        } else {
+            value_array.push(Value::Literal(reader.read_digit()?));
+            Ok(value_array.len() - 1)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NumberTree {
+    values: Vec<Value>,
+    root_index: usize,
+}
+
+impl NumberTree {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let mut reader = StringReader::new(input.trim());
+        let mut values = Vec::new();
+        let root_index = NumberPair::parse(&mut reader, &mut values, 1)?;
+        if reader.peek_char().is_some() {
+            return Err(ParseError::new(reader.span(), "end of line"));
+        }
+        Ok(NumberTree { values, root_index })
+    }
+
+    fn create_literal(&mut self, value: i64) -> usize {
+        self.values.push(Value::Literal(value));
+        self.values.len() - 1
+    }
+
+    fn add(&self, other: &NumberTree) -> NumberTree {
+        let mut values = Vec::with_capacity(self.values.len() + other.values.len() + 1);
+        values.extend(&self.values);
+
+        let other_start_index = values.len();
+        values.extend(&other.values);
+        for value in values.iter_mut().skip(other_start_index) {
+            if let Value::Pair(pair) = value {
+                pair.x += other_start_index;
+                pair.y += other_start_index;
+            }
+        }
+
+        let root_index = values.len();
+        values.push(Value::Pair(NumberPair::new(
+            self.root_index,
+            other_start_index + other.root_index,
+        )));
+        let mut result_tree = NumberTree { values, root_index };
+        result_tree.reduce();
+        result_tree
+    }
+
+    fn get_literal_parent_at(&self, at_index: usize) -> Option<(usize, bool)> {
+        let root = &self.values[self.root_index];
+        if let Value::Pair(_) = root {
+            let mut stack: Vec<(usize, bool)> = Vec::new();
+            stack.push((self.root_index, false));
+            stack.push((self.root_index, true));
+
+            let mut index = 0;
+            while let Some((cur_pair, is_left)) = stack.pop() {
+                if let Value::Pair(parent_pair) = self.values[cur_pair] {
+                    let cur_value = if is_left {
+                        parent_pair.x
+                    } else {
+                        parent_pair.y
+                    };
+                    match self.values[cur_value] {
+                        Value::Pair(_) => {
+                            stack.push((cur_value, false));
+                            stack.push((cur_value, true));
+                        }
+                        Value::Literal(_) => {
+                            if index == at_index {
+                                return Some((cur_pair, is_left));
+                            }
+                            index += 1;
+                        }
+                    }
+                }
+            }
+
+            None
+        } else if at_index == 0 {
+            Some((self.root_index, false))
+        } else {
+            None
+        }
+    }
+
+    fn add_to_literal(&mut self, index: usize, value: i64) {
+        let parent_opt = self.get_literal_parent_at(index);
+        if parent_opt.is_none() {
+            return;
+        }
+
+        let (parent_index, is_left) = parent_opt.unwrap();
+        if let Value::Pair(pair) = self.values[parent_index] {
+            if is_left {
+                if let Value::Literal(literal) = self.values[pair.x] {
+                    self.values[pair.x] = Value::Literal(literal + value);
+                } else {
+                    panic!("Expected literal but got pair");
+                }
+            } else {
+                if let Value::Literal(literal) = self.values[pair.y] {
+                    self.values[pair.y] = Value::Literal(literal + value);
+                } else {
+                    panic!("Expected literal but got pair");
+                }
+            }
+        } else {
+            panic!("Expected parent to be a pair");
+        }
+    }
+
+    fn explode(&mut self) -> bool {
+        if let Value::Literal(_) = &self.values[self.root_index] {
+            return false;
+        }
+
+        let mut stack: Vec<(usize, bool, usize)> = Vec::new();
+        stack.push((self.root_index, false, 1));
+        stack.push((self.root_index, true, 1));
+
+        let mut literal_index = 0;
+        while let Some((cur_pair, is_left, depth)) = stack.pop() {
+            if let Value::Pair(parent_pair) = self.values[cur_pair] {
+                let cur_value = if is_left {
+                    parent_pair.x
+                } else {
+                    parent_pair.y
+                };
+                match self.values[cur_value] {
+                    Value::Pair(cur_pair) => {
+                        if depth < 4 {
+                            stack.push((cur_value, false, depth + 1));
+                            stack.push((cur_value, true, depth + 1));
+                        } else {
+                            if let Value::Literal(literal) = self.values[cur_pair.x] {
+                                if literal_index > 0 {
+                                    self.add_to_literal(literal_index - 1, literal);
+                                }
+                                literal_index += 1;
+                            } else {
+                                panic!("Expected literal but got pair");
+                            }
+
+                            if let Value::Literal(literal) = self.values[cur_pair.y] {
+                                self.add_to_literal(literal_index + 1, literal);
+                            } else {
+                                panic!("Expected literal but got pair");
+                            }
+
+                            self.values[cur_value] = Value::Literal(0);
+                            return true;
+                        }
+                    }
+                    Value::Literal(_) => {
+                        literal_index += 1;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn split(&mut self) -> bool {
+        if let Value::Literal(literal) = self.values[self.root_index] {
+            // Special case for if the root is a literal
+            if literal > 9 {
+                let x = self.create_literal(literal / 2);
+                let y = self.create_literal(literal - literal / 2);
+                self.values[self.root_index] = Value::Pair(NumberPair::new(x, y));
+                return true;
+            }
+            return false;
+        }
+
+        let mut stack: Vec<(usize, bool)> = Vec::new();
+        stack.push((self.root_index, false));
+        stack.push((self.root_index, true));
+
+        while let Some((cur_pair, is_left)) = stack.pop() {
+            if let Value::Pair(parent_pair) = self.values[cur_pair] {
+                let cur_value = if is_left {
+                    parent_pair.x
+                } else {
+                    parent_pair.y
+                };
+                match self.values[cur_value] {
+                    Value::Pair(_) => {
+                        stack.push((cur_value, false));
+                        stack.push((cur_value, true));
+                    }
+                    Value::Literal(literal) => {
+                        if literal > 9 {
+                            let x = self.create_literal(literal / 2);
+                            let y = self.create_literal(literal - literal / 2);
+                            self.values[cur_value] = Value::Pair(NumberPair::new(x, y));
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn reduce(&mut self) {
+        loop {
+            if self.explode() {
+                continue;
+            }
+
+            if self.split() {
+                continue;
+            }
+
+            break;
+        }
+    }
+
+    fn magnitude(&self) -> i64 {
+        self.magnitude_helper(self.root_index)
+    }
+
+    fn magnitude_helper(&self, index: usize) -> i64 {
+        let mut mag: i64 = 0;
+        match &self.values[index] {
+            Value::Literal(literal) => {
+                mag += *literal;
+            }
+            Value::Pair(pair) => {
+                mag += 3 * self.magnitude_helper(pair.x);
+                mag += 2 * self.magnitude_helper(pair.y);
+            }
+        }
+
+        mag
+    }
+}
+
+struct StringReader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> StringReader<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    // The character at the read position, for error reporting
+    fn span(&self) -> &'a str {
+        parse::char_at(self.input, self.pos)
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek_char() != Some(expected) {
+            return Err(ParseError::new(self.span(), format!("'{}'", expected)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn read_digit(&mut self) -> Result<i64, ParseError> {
+        match self.peek_char().and_then(|c| c.to_digit(10)) {
+            Some(digit) => {
+                self.pos += 1;
+                Ok(digit as i64)
+            }
+            None => Err(ParseError::new(self.span(), "a digit or '['")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<NumberTree>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(NumberTree::new)
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<NumberTree>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(number_trees: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(number_trees).into()
+    }
+
+    fn part2(number_trees: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(number_trees).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day18>(EXAMPLE, 1), Ok(Answer::Int(4140)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day18>(EXAMPLE, 2), Ok(Answer::Int(3993)));
+    }
+
+    #[test]
+    fn rejects_unreduced_numbers() {
+        let expected = |input: &str| solve::<Day18>(input, 1).unwrap_err().expected;
+        assert_eq!(
+            expected("[[[[[1,2],3],4],5],6]"),
+            "a pair nested at most 4 deep"
+        );
+        assert_eq!(expected("[10,2]"), "','");
+        assert_eq!(expected("[-1,2]"), "a digit or '['");
+    }
+}
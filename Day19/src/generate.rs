@@ -0,0 +1,72 @@
+use aoc_common::generate::{Generated, Rng};
+use geometry::{Point3, Rotation, Vector3};
+
+// How far a scanner can see along each axis
+const RANGE: i64 = 1000;
+
+fn offset(rng: &mut Rng, range: i64) -> Vector3 {
+    Vector3::new(
+        rng.range(-range..=range),
+        rng.range(-range..=range),
+        rng.range(-range..=range),
+    )
+}
+
+fn sees(scanner: Point3, beacon: Point3) -> bool {
+    scanner.chebyshev_distance(beacon) <= RANGE
+}
+
+// `size` is the number of scanners. Scanner 0 sits at the origin facing the default way, and
+// each later one is placed near an earlier one with enough beacons in both of their views
+// to be matched up, so the beacons and scanner positions are known.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let rotations = Rotation::all();
+    let mut scanners = vec![(Point3::origin(), rotations[0])];
+    let mut beacons: Vec<Point3> = Vec::new();
+    for _ in 1..size.unwrap_or(25).max(1) {
+        let (near, _) = *rng.choose(&scanners);
+        let position = near + offset(rng, RANGE * 11 / 10);
+        let shared = rng.range(12..=16);
+        while beacons
+            .iter()
+            .filter(|&&beacon| sees(near, beacon) && sees(position, beacon))
+            .count()
+            < shared as usize
+        {
+            let beacon = position + offset(rng, RANGE);
+            if sees(near, beacon) {
+                beacons.push(beacon);
+            }
+        }
+        for _ in 0..rng.range(0..=10) {
+            beacons.push(position + offset(rng, RANGE));
+        }
+        scanners.push((position, *rng.choose(&rotations)));
+    }
+    if scanners.len() == 1 {
+        beacons.extend((0..12).map(|_| Point3::origin() + offset(rng, RANGE)));
+    }
+
+    // Sorted rather than hashed, so a seed always gives the same report order
+    beacons.sort();
+    beacons.dedup();
+    let mut reports = Vec::new();
+    for (index, &(position, rotation)) in scanners.iter().enumerate() {
+        let mut report = format!("--- scanner {} ---\n", index);
+        for &beacon in beacons.iter().filter(|&&beacon| sees(position, beacon)) {
+            let seen = rotation * (beacon - position);
+            report += &format!("{},{},{}\n", seen.x(), seen.y(), seen.z());
+        }
+        reports.push(report);
+    }
+
+    let mut farthest = 0;
+    for (a, _) in &scanners {
+        for (b, _) in &scanners {
+            farthest = farthest.max(a.manhattan_distance(*b));
+        }
+    }
+    Generated::new(reports.join("\n"))
+        .with_answer(1, beacons.len())
+        .with_answer(2, farthest)
+}
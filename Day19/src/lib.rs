@@ -0,0 +1,260 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parallel, parse, Answer, ParseError, Solution};
+use geometry::{Point3, Rotation, Vector3};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+aoc_common::config! {
+    pub struct Day19Config {
+        // Beacons two scanners must have in common to be considered overlapping
+        min_overlap: usize = 12,
+    }
+    check = check_config;
+}
+
+fn check_config(config: &Day19Config) -> Result<(), String> {
+    if config.min_overlap == 0 {
+        return Err("min_overlap must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn part1(omega_scanner: &Scanner) -> usize {
+    omega_scanner.points.len()
+}
+
+fn part2(scanner_offset_from_zero: &[Vector3]) -> i64 {
+    let mut largest_manhattan_distance = 0;
+    for offsets in scanner_offset_from_zero.iter().combinations(2) {
+        let distance = (*offsets[0] - *offsets[1]).manhattan();
+        if distance > largest_manhattan_distance {
+            largest_manhattan_distance = distance;
+        }
+    }
+
+    largest_manhattan_distance
+}
+
+// Fails with the index of a scanner that doesn't overlap any of the others
+fn create_omega_scanner(
+    scanners: &[Scanner],
+    min_overlap: usize,
+) -> Result<(Scanner, Vec<Vector3>), usize> {
+    let rotations = Rotation::all();
+    let mut rotated_scanners: Vec<Vec<Scanner>> = Vec::new();
+    for scanner in scanners {
+        let rotated: Vec<Scanner> = rotations.iter().map(|&r| scanner.rotated(r)).collect();
+        rotated_scanners.push(rotated);
+    }
+
+    let mut omega_scanner: Scanner = scanners[0].clone();
+    let mut scanners_added: Vec<usize> = Vec::new();
+    let mut scanners_properly_rotated: Vec<Scanner> = vec![Scanner::default(); scanners.len()];
+    let mut scanner_offset_from_zero: Vec<Vector3> = vec![Vector3::zero(); scanners.len()];
+    scanners_added.push(0);
+    scanners_properly_rotated[0] = scanners[0].clone();
+
+    let mut start_scanners_added_index = 0;
+    while scanners_added.len() < scanners.len() {
+        let mut found_any_matches = false;
+        let new_start_canners_added_index = scanners_added.len();
+        for i in 1..scanners.len() {
+            if scanners_added.contains(&i) {
+                continue;
+            }
+
+            // The rotations are tried independently, so they can be spread across threads.
+            // The first match in rotation order wins either way.
+            let found_match = parallel::find_map(&rotated_scanners[i], |cur_scanner| {
+                scanners_added
+                    .iter()
+                    .skip(start_scanners_added_index)
+                    .find_map(|&base_scanner_index| {
+                        let base_scanner = &scanners_properly_rotated[base_scanner_index];
+                        let offset = base_scanner.matches(cur_scanner, min_overlap)?;
+                        Some((cur_scanner, base_scanner_index, offset))
+                    })
+            });
+            if let Some((cur_scanner, base_scanner_index, offset)) = found_match {
+                let offset_from_zero = scanner_offset_from_zero[base_scanner_index] + offset;
+                scanner_offset_from_zero[i] = offset_from_zero;
+                scanners_properly_rotated[i] = cur_scanner.clone();
+
+                omega_scanner = omega_scanner.combine(cur_scanner, &offset_from_zero);
+                scanners_added.push(i);
+                found_any_matches = true;
+            }
+        }
+        start_scanners_added_index = new_start_canners_added_index;
+        if scanners_added.len() < scanners.len() && !found_any_matches {
+            return Err((1..scanners.len())
+                .find(|i| !scanners_added.contains(i))
+                .unwrap());
+        }
+    }
+
+    Ok((omega_scanner, scanner_offset_from_zero))
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    points: Vec<Point3>,
+}
+
+impl Scanner {
+    fn new(lines: &[&str]) -> Result<Self, ParseError> {
+        let points: Vec<Point3> = lines
+            .iter()
+            .map(|line| {
+                let mut parts = line.split(",");
+                let x = parse::number(parse::next(&mut parts, line, "','")?)?;
+                let y = parse::number(parse::next(&mut parts, line, "','")?)?;
+                let z = parse::number(parse::next(&mut parts, line, "','")?)?;
+                parse::end(&mut parts)?;
+                Ok(Point3::new(x, y, z))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Scanner { points })
+    }
+
+    fn default() -> Self {
+        Scanner { points: Vec::new() }
+    }
+
+    fn rotated(&self, rotation: Rotation) -> Self {
+        let points = self.points.iter().map(|&point| rotation * point).collect();
+        Scanner { points }
+    }
+
+    fn matches(&self, other: &Scanner, min_overlap: usize) -> Option<Vector3> {
+        for point_pair in self.points.iter().cartesian_product(&other.points) {
+            let (&p1, &p2) = point_pair;
+            let offset = p1 - p2;
+
+            let num_matching = self
+                .points
+                .iter()
+                .filter(|&point| {
+                    other
+                        .points
+                        .iter()
+                        .map(|&p| p + offset)
+                        .any(|p| p == *point)
+                })
+                .count();
+
+            if num_matching >= min_overlap {
+                return Some(offset);
+            }
+        }
+
+        None
+    }
+
+    fn combine(&self, other: &Scanner, offset: &Vector3) -> Scanner {
+        let mut points_set: HashSet<Point3> =
+            HashSet::with_capacity(self.points.len() + other.points.len());
+        points_set.extend(&self.points);
+
+        for point in &other.points {
+            points_set.insert(*point + *offset);
+        }
+
+        let points = points_set.iter().cloned().collect();
+        Scanner { points }
+    }
+}
+
+fn is_scanner_header(line: &str) -> bool {
+    line.starts_with("--- scanner ") && line.ends_with(" ---")
+}
+
+// Each scanner along with its header line
+fn read_scanners(input: &str) -> Result<Vec<(&str, Scanner)>, ParseError> {
+    let mut scanners: Vec<(&str, Scanner)> = Vec::new();
+    let lines = input.lines().map(|line| line.trim()).collect::<Vec<&str>>();
+    let mut i = 0;
+    while i < lines.len() {
+        let header = lines[i];
+        if header.is_empty() {
+            i += 1;
+            continue;
+        }
+        if !is_scanner_header(header) {
+            return Err(ParseError::new(header, "'--- scanner N ---'"));
+        }
+
+        i += 1;
+        let group_start = i;
+        while i < lines.len() && !lines[i].is_empty() && !is_scanner_header(lines[i]) {
+            i += 1;
+        }
+        scanners.push((header, Scanner::new(&lines[group_start..i])?));
+    }
+
+    if scanners.is_empty() {
+        return Err(ParseError::new(input, "'--- scanner 0 ---'"));
+    }
+    Ok(scanners)
+}
+
+fn parse(input: &str, min_overlap: usize) -> Result<(Scanner, Vec<Vector3>), ParseError> {
+    let (headers, scanners): (Vec<&str>, Vec<Scanner>) = read_scanners(input)?.into_iter().unzip();
+    create_omega_scanner(&scanners, min_overlap)
+        .map_err(|i| ParseError::new(headers[i], "a scanner that overlaps the others"))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Scanner, Vec<Vector3>);
+    type Config = Day19Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input, config.min_overlap)
+    }
+
+    fn part1((omega_scanner, _): &Self::Input, _config: &Self::Config) -> Answer {
+        part1(omega_scanner).into()
+    }
+
+    fn part2((_, scanner_offset_from_zero): &Self::Input, _config: &Self::Config) -> Answer {
+        part2(scanner_offset_from_zero).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day19>(EXAMPLE, 1), Ok(Answer::Int(79)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day19>(EXAMPLE, 2), Ok(Answer::Int(3621)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..2 {
+            let generated = Day19::generate(&mut Rng::new(seed), Some(3));
+            for (part, answer) in &generated.answers {
+                assert_eq!(solve::<Day19>(&generated.input, *part).as_ref(), Ok(answer));
+            }
+        }
+    }
+}
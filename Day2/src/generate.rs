@@ -0,0 +1,15 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+// `size` is the number of commands
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let commands = (0..size.unwrap_or(1000)).map(|_| {
+        // Weighted like the puzzle's so the submarine mostly goes forward and down
+        let direction = match rng.below(10) {
+            0..=4 => "forward",
+            5..=7 => "down",
+            _ => "up",
+        };
+        format!("{} {}", direction, rng.range(1..=9))
+    });
+    Generated::new(lines(commands))
+}
@@ -0,0 +1,213 @@
+mod generate;
+pub mod submarine;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use submarine::{Aimed, Command, Movement, Plain, Submarine};
+
+// The answer is the position multiplied by the depth, which is only sure to fit in an i128
+fn solve(commands: &[Command], movement: impl Movement) -> i128 {
+    let mut submarine = Submarine::new(movement);
+    submarine.run(commands).expect("Checked when parsed");
+    let state = submarine.state();
+    state.position as i128 * state.depth as i128
+}
+
+// The index of the first command that would overflow with the given movement
+fn first_overflow(commands: &[(usize, Command)], movement: impl Movement) -> Option<usize> {
+    let mut submarine = Submarine::new(movement);
+    let commands: Vec<Command> = commands.iter().map(|&(_, command)| command).collect();
+    submarine.run(&commands).err()
+}
+
+fn parse_command(line: &str) -> Result<Command, ParseError> {
+    // Split line by whitespace
+    let mut words = line.split_whitespace();
+
+    // Get the command, then the amount for the ones that take one
+    let command = parse::next(&mut words, line, "a command")?;
+    let mut amount = || -> Result<i64, ParseError> {
+        parse::number(parse::next(&mut words, line, "an amount")?)
+    };
+    let command = match command {
+        "forward" => Command::Forward(amount()?),
+        "down" => Command::Down(amount()?),
+        "up" => Command::Up(amount()?),
+        "back" => Command::Back(amount()?),
+        "turn" => Command::Turn,
+        "reset" => Command::Reset,
+        word => {
+            return Err(ParseError::new(
+                word,
+                "'forward', 'down', 'up', 'back', 'turn' or 'reset'",
+            ))
+        }
+    };
+    parse::end(&mut words)?;
+
+    Ok(command)
+}
+
+// Every command in the course with its line number, along with an error for each line that
+// isn't a command, so a broken course can be fixed in one go. Blank lines are allowed. A
+// course that would take either part's position, depth or aim past an i64 is an error at
+// the command where it would.
+pub fn lint(input: &str) -> (Vec<(usize, Command)>, Vec<ParseError>) {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut commands = Vec::new();
+    let mut errors = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_command(line) {
+            Ok(command) => commands.push((i + 1, command)),
+            Err(e) => errors.push(e.locate(input)),
+        }
+    }
+
+    if errors.is_empty() {
+        let overflow = [
+            first_overflow(&commands, Plain),
+            first_overflow(&commands, Aimed),
+        ];
+        if let Some(&i) = overflow.iter().flatten().min() {
+            let line = lines[commands[i].0 - 1].trim();
+            let expected = "a command that keeps the position, depth and aim within 64 bits";
+            errors.push(ParseError::new(line, expected).locate(input));
+        }
+    }
+    (commands, errors)
+}
+
+fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    let (commands, errors) = lint(input);
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(commands.into_iter().map(|(_, command)| command).collect()),
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(commands: &Self::Input, _config: &Self::Config) -> Answer {
+        solve(commands, Plain).into()
+    }
+
+    fn part2(commands: &Self::Input, _config: &Self::Config) -> Answer {
+        solve(commands, Aimed).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+    use submarine::Stop;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day2>(EXAMPLE, 1), Ok(Answer::Int(150)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day2>(EXAMPLE, 2), Ok(Answer::Int(900)));
+    }
+
+    #[test]
+    fn extra_commands() {
+        let course = "forward 5\ndown 5\nreset\nforward 4\nturn\nback 2\ndown 3\n";
+        assert_eq!(solve::<Day2>(course, 1), Ok(Answer::Int(18)));
+
+        let course = "down 2\nforward 3\nturn\nforward 1\nback 2\n";
+        let mut submarine = Submarine::new(Aimed).recording();
+        submarine.run(&parse(course).unwrap()).unwrap();
+        let positions: Vec<(i64, i64)> = submarine
+            .trajectory()
+            .iter()
+            .map(|state| (state.position, state.depth))
+            .collect();
+        assert_eq!(positions, [(0, 0), (0, 0), (3, 6), (3, 6), (2, 8), (4, 4)]);
+    }
+
+    #[test]
+    fn lints_every_line() {
+        let (commands, errors) = lint("forward 5\nsideways 3\n\ndown\nup 2 3\nturn\n");
+        assert_eq!(commands, [(1, Command::Forward(5)), (6, Command::Turn)]);
+        let lines: Vec<usize> = errors
+            .iter()
+            .map(|e| e.location.as_ref().unwrap().line)
+            .collect();
+        assert_eq!(lines, [2, 4, 5]);
+    }
+
+    #[test]
+    fn replays_to_a_stop() {
+        let commands = parse(EXAMPLE).unwrap();
+        let mut submarine = Submarine::new(Aimed);
+        assert_eq!(submarine.run_until(&commands, Stop::Step(4)), Some(4));
+        let state = submarine.state();
+        assert_eq!((state.position, state.depth, state.aim), (13, 40, 2));
+
+        // The depth goes from 40 to 60 on the last step
+        let mut submarine = Submarine::new(Aimed);
+        assert_eq!(
+            submarine.run_until(&commands, Stop::DepthCrosses(50)),
+            Some(6)
+        );
+        assert_eq!(submarine.state().depth, 60);
+        let mut submarine = Submarine::new(Aimed);
+        assert_eq!(submarine.run_until(&commands, Stop::DepthCrosses(61)), None);
+
+        // Starting on the depth counts as reaching it
+        let mut submarine = Submarine::new(Aimed);
+        assert_eq!(
+            submarine.run_until(&commands, Stop::DepthCrosses(0)),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn rejects_overflowing_courses() {
+        let course = "forward 9223372036854775807\ndown 2\n";
+        assert_eq!(
+            solve::<Day2>(course, 1),
+            Ok(Answer::Int(i64::MAX as i128 * 2))
+        );
+        let (_, errors) = lint("forward 9223372036854775807\ndown 2\nforward 1\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location.as_ref().unwrap().line, 3);
+        assert!(parse("back -9223372036854775808").is_err());
+        assert!(parse("turn\nforward -9223372036854775808").is_err());
+
+        // The biggest positions and depths still multiply out
+        let course = "forward 9223372036854775807\ndown 9223372036854775807\n";
+        assert_eq!(
+            solve::<Day2>(course, 1),
+            Ok(Answer::Int(i64::MAX as i128 * i64::MAX as i128))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_commands() {
+        assert!(parse("sideways 3").is_err());
+        assert!(parse("turn 3").is_err());
+        assert!(parse("back").is_err());
+    }
+}
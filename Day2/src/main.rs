@@ -1,78 +1,131 @@
-use std::fs;
+use aoc_common::input::{self, Source};
+use day_2::submarine::{Aimed, Command, Movement, Plain, Stop, Submarine};
+use std::env;
+use std::process;
 
-fn part1(lines : &Vec<String>) {
-    // Print part 1 header
-    println!("Part 1:");
+const USAGE: &str = "Usage:
+    day_2 --lint <path|->
+    day_2 --replay <path|-> [--step <n> | --depth <d>] [--plain]";
 
-    let mut pos = 0;
-    let mut depth = 0;
-
-    // Loop through all lines
-    for line in lines.iter() {
-        // Split line by whitespace
-        let mut words: Vec<String> = line.split_whitespace().map(|x| x.to_string()).collect();
-        if words.len() != 2 {
-            continue;
-        }
-
-        // Get the direction and the amount
-        let direction = words.remove(0);
-        let amount = words.remove(0).parse::<i32>().unwrap();
-        match direction.as_ref() {
-            "forward" => pos += amount,
-            "up" => depth -= amount,
-            "down" => depth += amount,
-            _ => panic!("Invalid direction"),
-        };
+// The course's commands with their line numbers, or every error in it
+fn read_course(path: &str) -> Result<Vec<(usize, Command)>, String> {
+    let (path, text) = input::read(&Source::from_input_arg(path), &[])?;
+    let (commands, errors) = day_2::lint(&text);
+    if errors.is_empty() {
+        return Ok(commands);
     }
 
-    // Print the final position and depth
-    println!("Final position: {}", pos);
-    println!("Final depth: {}", depth);
+    for e in &errors {
+        eprintln!("{}\n", e.clone().with_file(&path));
+    }
+    Err(format!(
+        "{} of {} lines in {} aren't valid commands",
+        errors.len(),
+        errors.len() + commands.len(),
+        path.display()
+    ))
+}
 
-    // The answer is the position multiplied by the depth
-    println!("Answer: {}", pos * depth);
+// day_2 --lint <path|->
+fn lint(args: &[String]) -> Result<(), String> {
+    let path = match args {
+        [path] => path,
+        _ => return Err(USAGE.to_string()),
+    };
+    let commands = read_course(path)?;
+    println!("{} commands, all valid", commands.len());
+    Ok(())
 }
 
-fn part2(lines : &Vec<String>) {
-    // Print part 2 header
-    println!("Part 2:");
+fn replay_with(movement: impl Movement, commands: &[(usize, Command)], stop: Option<Stop>) {
+    let mut submarine = Submarine::new(movement);
+    let steps: Vec<Command> = commands.iter().map(|&(_, command)| command).collect();
+    let step = match stop {
+        Some(stop) => submarine.run_until(&steps, stop),
+        None => {
+            submarine.run(&steps).expect("Checked by the lint");
+            Some(steps.len())
+        }
+    };
 
-    let mut aim = 0;
-    let mut pos = 0;
-    let mut depth = 0;
+    let state = submarine.state();
+    let at = match step {
+        Some(0) => "At the start".to_string(),
+        Some(step) => format!("After step {} (line {})", step, commands[step - 1].0),
+        None => match stop {
+            Some(Stop::DepthCrosses(depth)) => format!("Never crosses depth {}, at the end", depth),
+            Some(Stop::Step(step)) => {
+                format!(
+                    "The course ends after step {}, before step {}",
+                    steps.len(),
+                    step
+                )
+            }
+            None => unreachable!("Runs to the end without a stop"),
+        },
+    };
+    println!(
+        "{}: position {}, depth {}, aim {}",
+        at, state.position, state.depth, state.aim
+    );
+}
 
-    // Loop through all lines
-    for line in lines.iter() {
-        // Split line by whitespace
-        let mut words: Vec<String> = line.split_whitespace().map(|x| x.to_string()).collect();
-        if words.len() != 2 {
-            continue;
-        }
+// day_2 --replay <path|-> [--step <n> | --depth <d>] [--plain]
+fn replay(args: &[String]) -> Result<(), String> {
+    let (path, rest) = args.split_first().ok_or(USAGE)?;
+    let mut stop = None;
+    let mut plain = false;
 
-        // Get the direction and the amount
-        let direction = words.remove(0);
-        let amount = words.remove(0).parse::<i32>().unwrap();
-        match direction.as_ref() {
-            "forward" => { pos += amount; depth += amount * aim; },
-            "up" => aim -= amount,
-            "down" => aim += amount,
-            _ => panic!("Invalid direction"),
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        let mut value = |name: &str| {
+            rest.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", name))
         };
+        match arg.as_str() {
+            "--step" => {
+                let step = value("--step")?;
+                let step = step
+                    .parse()
+                    .map_err(|_| format!("Invalid step '{}'", step))?;
+                stop = Some(Stop::Step(step));
+            }
+            "--depth" => {
+                let depth = value("--depth")?;
+                let depth = depth
+                    .parse()
+                    .map_err(|_| format!("Invalid depth '{}'", depth))?;
+                stop = Some(Stop::DepthCrosses(depth));
+            }
+            "--plain" => plain = true,
+            _ => return Err(USAGE.to_string()),
+        }
     }
 
-    // Print the final position and depth
-    println!("Final position: {}", pos);
-    println!("Final depth: {}", depth);
-
-    // The answer is the position multiplied by the depth
-    println!("Answer: {}", pos * depth);
+    // Part 2's reading of the commands unless asked for part 1's
+    let commands = read_course(path)?;
+    if plain {
+        replay_with(Plain, &commands, stop);
+    } else {
+        replay_with(Aimed, &commands, stop);
+    }
+    Ok(())
 }
 
 fn main() {
-    // Read all lines of input.txt
-    let input = fs::read_to_string("input.txt").expect("Unable to read file");
-    let lines: Vec<String> = input.lines().map(|x| x.to_string()).collect();
-    part1(&lines);
-    part2(&lines);
-}
\ No newline at end of file
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("--lint") => lint(&args[1..]),
+        Some("--replay") => replay(&args[1..]),
+        _ => {
+            aoc_common::run_day::<day_2::Day2>(2);
+            return;
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,174 @@
+// A command from the course. The puzzle only uses forward, down and up, and the rest are
+// for courses from newer submarines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+    // Goes backwards along the heading, undoing a forward of the same distance
+    Back(i64),
+    // Faces the other way, so forward and back swap directions
+    Turn,
+    // Returns to the start of the course
+    Reset,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub position: i64,
+    pub depth: i64,
+    pub aim: i64,
+    // 1 while facing the way the course starts, -1 once turned around
+    pub heading: i64,
+}
+
+impl Default for State {
+    fn default() -> State {
+        State {
+            position: 0,
+            depth: 0,
+            aim: 0,
+            heading: 1,
+        }
+    }
+}
+
+// How forward, down and up move the submarine, which part 2 finds out isn't how part 1
+// thought they did. Both return None, leaving the state as it was, if it would overflow.
+pub trait Movement {
+    // Goes `distance` along the heading, or backwards when it's negative
+    fn advance(&self, state: &mut State, distance: i64) -> Option<()>;
+    // Down by `amount`, or up when it's negative
+    fn dive(&self, state: &mut State, amount: i64) -> Option<()>;
+}
+
+// Part 1: down and up change the depth directly
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl Movement for Plain {
+    fn advance(&self, state: &mut State, distance: i64) -> Option<()> {
+        state.position = state
+            .position
+            .checked_add(state.heading.checked_mul(distance)?)?;
+        Some(())
+    }
+
+    fn dive(&self, state: &mut State, amount: i64) -> Option<()> {
+        state.depth = state.depth.checked_add(amount)?;
+        Some(())
+    }
+}
+
+// Part 2: down and up change the aim, and going forward changes the depth by the aim
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aimed;
+
+impl Movement for Aimed {
+    fn advance(&self, state: &mut State, distance: i64) -> Option<()> {
+        let position = state
+            .position
+            .checked_add(state.heading.checked_mul(distance)?)?;
+        state.depth = state.depth.checked_add(state.aim.checked_mul(distance)?)?;
+        state.position = position;
+        Some(())
+    }
+
+    fn dive(&self, state: &mut State, amount: i64) -> Option<()> {
+        state.aim = state.aim.checked_add(amount)?;
+        Some(())
+    }
+}
+
+// Where to stop a replay of the course
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // After this many commands, with 0 being the start
+    Step(usize),
+    // The first time a command takes the depth from one side of this to the other, or onto
+    // it. A submarine already at this depth has reached it after 0 commands.
+    DepthCrosses(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Submarine<M> {
+    movement: M,
+    state: State,
+    // The state before the first command and after each one, when recording
+    trajectory: Option<Vec<State>>,
+}
+
+impl<M: Movement> Submarine<M> {
+    pub fn new(movement: M) -> Submarine<M> {
+        Submarine {
+            movement,
+            state: State::default(),
+            trajectory: None,
+        }
+    }
+
+    // Keeps every state the submarine passes through, for trajectory()
+    pub fn recording(mut self) -> Submarine<M> {
+        self.trajectory = Some(vec![self.state]);
+        self
+    }
+
+    // None if the command would take the position, depth or aim past what an i64 holds, in
+    // which case the submarine stays where it was
+    pub fn execute(&mut self, command: Command) -> Option<()> {
+        let state = &mut self.state;
+        match command {
+            Command::Forward(distance) => self.movement.advance(state, distance)?,
+            Command::Back(distance) => self.movement.advance(state, distance.checked_neg()?)?,
+            Command::Down(amount) => self.movement.dive(state, amount)?,
+            Command::Up(amount) => self.movement.dive(state, amount.checked_neg()?)?,
+            Command::Turn => state.heading = -state.heading,
+            Command::Reset => *state = State::default(),
+        }
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.push(self.state);
+        }
+        Some(())
+    }
+
+    // Runs every command, or up to the first that would overflow, returning its index
+    pub fn run(&mut self, commands: &[Command]) -> Result<(), usize> {
+        for (i, &command) in commands.iter().enumerate() {
+            self.execute(command).ok_or(i)?;
+        }
+        Ok(())
+    }
+
+    // Runs commands until `stop`, returning how many were run to get there, or None when the
+    // course ends first. A command that would overflow ends the course early, which lint()
+    // reports beforehand.
+    pub fn run_until(&mut self, commands: &[Command], stop: Stop) -> Option<usize> {
+        if stop == Stop::Step(0) || stop == Stop::DepthCrosses(self.state.depth) {
+            return Some(0);
+        }
+        for (i, &command) in commands.iter().enumerate() {
+            let before = self.state.depth;
+            self.execute(command)?;
+            let after = self.state.depth;
+            let reached = match stop {
+                Stop::Step(step) => i + 1 == step,
+                Stop::DepthCrosses(depth) => {
+                    (before < depth && after >= depth) || (before > depth && after <= depth)
+                }
+            };
+            if reached {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    // Empty unless the submarine is recording
+    pub fn trajectory(&self) -> &[State] {
+        self.trajectory.as_deref().unwrap_or(&[])
+    }
+}
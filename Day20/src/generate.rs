@@ -0,0 +1,20 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the side of the square input image. When the algorithm turns dark surroundings
+// light it also turns light surroundings dark, or the lit pixels would be infinite.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let mut input: String = algorithm.into_iter().collect();
+    input += "\n\n";
+    let side = size.unwrap_or(100);
+    for _ in 0..side {
+        input.extend((0..side).map(|_| pixel(rng)));
+        input.push('\n');
+    }
+    Generated::new(input)
+}
@@ -0,0 +1,180 @@
+mod generate;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use std::collections::HashSet;
+use tracing::{debug, trace};
+
+pub type Image = HashSet<(i64, i64)>;
+
+aoc_common::config! {
+    pub struct Day20Config {
+        // Times to apply the enhancement algorithm for each part
+        part1_passes: usize = 2,
+        part2_passes: usize = 50,
+    }
+}
+
+fn enhance(decoder: &[bool], input_image: &Image, num_passes: usize) -> usize {
+    let mut image = input_image.clone();
+    animate_image(&image, "Input image".to_string());
+    for i in 0..num_passes {
+        image = step_image(&image, decoder, i + 1);
+        debug!(pass = i + 1, lit = image.len());
+        trace!("after pass {}:\n{}", i + 1, render_image(&image));
+        let caption = format!("Pass {} of {}: {} lit", i + 1, num_passes, image.len());
+        animate_image(&image, caption);
+    }
+
+    image.len()
+}
+
+fn get_minmax(input_image: &Image) -> (i64, i64, i64, i64) {
+    let mut min_x = i64::MAX;
+    let mut max_x = i64::MIN;
+    let mut min_y = i64::MAX;
+    let mut max_y = i64::MIN;
+    for point in input_image.iter() {
+        if point.0 < min_x {
+            min_x = point.0;
+        }
+        if point.0 > max_x {
+            max_x = point.0;
+        }
+        if point.1 < min_y {
+            min_y = point.1;
+        }
+        if point.1 > max_y {
+            max_y = point.1;
+        }
+    }
+
+    (min_x, max_x, min_y, max_y)
+}
+
+// The lit pixels as rows of '#' and '.', trimmed to the ones that are lit
+fn render_image(image: &Image) -> String {
+    let (min_x, max_x, min_y, max_y) = get_minmax(image);
+
+    let mut text = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            text.push(if image.contains(&(x, y)) { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn animate_image(image: &Image, caption: String) {
+    animate::frame(|| {
+        Frame::from_text(caption, &render_image(image), |c| {
+            (c == '#').then_some(Color::White)
+        })
+    });
+}
+
+fn step_image(image: &Image, decoder: &[bool], step_num: usize) -> Image {
+    let (min_x, max_x, min_y, max_y) = get_minmax(image);
+
+    const RADIUS: i64 = 1;
+    let mut output_image: Image = Image::new();
+    let start_x = min_x - RADIUS;
+    let end_x = max_x + RADIUS;
+    let start_y = min_y - RADIUS;
+    let end_y = max_y + RADIUS;
+    for x in start_x..=end_x {
+        for y in start_y..=end_y {
+            let mut decoder_index: usize = 0;
+            for y1 in (y - 1)..=(y + 1) {
+                for x1 in (x - 1)..=(x + 1) {
+                    decoder_index <<= 1;
+                    if image.contains(&(x1, y1))
+                        || (decoder[0]
+                            && step_num.is_multiple_of(2)
+                            && (x1 < min_x || x1 > max_x || y1 < min_y || y1 > max_y))
+                    {
+                        decoder_index |= 1;
+                    }
+                }
+            }
+            if decoder[decoder_index] {
+                output_image.insert((x, y));
+            }
+        }
+    }
+
+    output_image
+}
+
+fn check_pixels(line: &str) -> Result<(), ParseError> {
+    match line.find(|c| c != '#' && c != '.') {
+        Some(i) => Err(ParseError::new(parse::char_at(line, i), "'#' or '.'")),
+        None => Ok(()),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<bool>, Image), ParseError> {
+    let decoder_line = input.lines().next().unwrap_or(input).trim_end();
+    let input_lines: Vec<&str> = input.lines().skip(2).map(|line| line.trim_end()).collect();
+
+    check_pixels(decoder_line)?;
+    if decoder_line.len() != 512 {
+        return Err(ParseError::new(decoder_line, "an algorithm of 512 pixels"));
+    }
+    let decoder: Vec<bool> = decoder_line.chars().map(|c| c == '#').collect();
+    let mut image: Image = Image::new();
+    for (y, line) in input_lines.iter().enumerate() {
+        check_pixels(line)?;
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                image.insert((x as i64, y as i64));
+            }
+        }
+    }
+
+    Ok((decoder, image))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+    type Config = Day20Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((decoder, image): &Self::Input, config: &Self::Config) -> Answer {
+        enhance(decoder, image, config.part1_passes).into()
+    }
+
+    fn part2((decoder, image): &Self::Input, config: &Self::Config) -> Answer {
+        enhance(decoder, image, config.part2_passes).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day20>(EXAMPLE, 1), Ok(Answer::Int(35)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day20>(EXAMPLE, 2), Ok(Answer::Int(3351)));
+    }
+}
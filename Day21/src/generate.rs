@@ -0,0 +1,10 @@
+use aoc_common::generate::{Generated, Rng};
+
+// The game always has two players, so `size` is unused
+pub fn generate(rng: &mut Rng, _size: Option<usize>) -> Generated {
+    Generated::new(format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.range(1..=10),
+        rng.range(1..=10)
+    ))
+}
@@ -0,0 +1,224 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+#[allow(unused)]
+use itertools::Itertools;
+use regex::Regex;
+
+aoc_common::config! {
+    pub struct Day21Config {
+        // Score that wins the practice game with the deterministic die
+        target_score: usize = 1000,
+        die_sides: usize = 100,
+        track_length: usize = 10,
+        // Score that wins with the Dirac die
+        dirac_target_score: usize = 21,
+    }
+    check = check_config;
+}
+
+fn check_config(config: &Day21Config) -> Result<(), String> {
+    if config.die_sides == 0 {
+        return Err("die_sides must be at least 1".to_string());
+    }
+    if config.track_length == 0 {
+        return Err("track_length must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn part1(player1_start: usize, player2_start: usize, config: &Day21Config) -> usize {
+    let mut game = GameState::new(player1_start, player2_start);
+    let mut die_value = 0;
+    let mut roll = || {
+        die_value = die_value % config.die_sides + 1;
+        die_value
+    };
+    let mut num_die_rolls = 0;
+    while game.highest_score() < config.target_score {
+        let die_roll = roll() + roll() + roll();
+        num_die_rolls += 3;
+        game = game.next_state(die_roll, config.track_length);
+    }
+
+    num_die_rolls * game.lowest_score()
+}
+
+fn part2(player1_start: usize, player2_start: usize, config: &Day21Config) -> usize {
+    let mut stack: Vec<GameState> = vec![GameState::new(player1_start, player2_start)];
+    let mut player1_wins = 0;
+    let mut player2_wins = 0;
+    let target = config.dirac_target_score;
+
+    while let Some(state) = stack.pop() {
+        for (die_roll, &universes) in UNIVERSES_PER_ROLL.iter().enumerate().skip(3) {
+            let mut next_state = state.next_state(die_roll, config.track_length);
+            next_state.num_universes *= universes;
+            if next_state.player1.score >= target || next_state.player2.score >= target {
+                if next_state.player1.score > next_state.player2.score {
+                    player1_wins += next_state.num_universes;
+                } else {
+                    player2_wins += next_state.num_universes;
+                }
+            } else {
+                stack.push(next_state);
+            }
+        }
+    }
+
+    if player1_wins > player2_wins {
+        player1_wins
+    } else {
+        player2_wins
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PlayerState {
+    score: usize,
+    loc: usize,
+}
+
+impl PlayerState {
+    fn new(loc: usize) -> PlayerState {
+        PlayerState { score: 0, loc }
+    }
+
+    fn next_state(&mut self, die_roll: usize, track_length: usize) {
+        let new_loc = ((self.loc + die_roll - 1) % track_length) + 1;
+        let new_score = self.score + new_loc;
+        self.loc = new_loc;
+        self.score = new_score;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GameState {
+    player1: PlayerState,
+    player2: PlayerState,
+    player1_turn: bool,
+    num_universes: usize,
+}
+
+// Ways three rolls of the three-sided Dirac die can add up to each total
+static UNIVERSES_PER_ROLL: &[usize] = &[0, 0, 0, 1, 3, 6, 7, 6, 3, 1];
+
+impl GameState {
+    fn new(player1_start: usize, player2_start: usize) -> GameState {
+        GameState {
+            player1: PlayerState::new(player1_start),
+            player2: PlayerState::new(player2_start),
+            player1_turn: true,
+            num_universes: 1,
+        }
+    }
+
+    fn highest_score(&self) -> usize {
+        self.player1.score.max(self.player2.score)
+    }
+
+    fn lowest_score(&self) -> usize {
+        self.player1.score.min(self.player2.score)
+    }
+
+    fn next_state(&self, die_roll: usize, track_length: usize) -> GameState {
+        let mut new_state = *self;
+        if new_state.player1_turn {
+            new_state.player1.next_state(die_roll, track_length);
+        } else {
+            new_state.player2.next_state(die_roll, track_length);
+        }
+        new_state.player1_turn = !new_state.player1_turn;
+
+        new_state
+    }
+}
+
+fn parse_position(re: &Regex, line: &str, track_length: usize) -> Result<usize, ParseError> {
+    let line = line.trim();
+    let cap = re
+        .captures(line)
+        .ok_or_else(|| ParseError::new(line, "'Player N starting position: P'"))?;
+    let position = cap.get(1).unwrap().as_str();
+    match parse::number(position)? {
+        position if (1..=track_length).contains(&position) => Ok(position),
+        _ => {
+            let expected = format!("a position from 1 to {}", track_length);
+            Err(ParseError::new(position, expected))
+        }
+    }
+}
+
+fn parse_input(input: &str, track_length: usize) -> Result<(usize, usize), ParseError> {
+    let mut lines = input.lines();
+
+    let re = Regex::new(r"^Player \d starting position: (\d+)$").unwrap();
+    let line = parse::next(&mut lines, input, "player 1")?;
+    let position1 = parse_position(&re, line, track_length)?;
+    let line = parse::next(&mut lines, input, "player 2")?;
+    let position2 = parse_position(&re, line, track_length)?;
+    Ok((position1, position2))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (usize, usize);
+    type Config = Day21Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse_input(input, config.track_length)
+    }
+
+    fn part1((player1_start, player2_start): &Self::Input, config: &Self::Config) -> Answer {
+        part1(*player1_start, *player2_start, config).into()
+    }
+
+    fn part2((player1_start, player2_start): &Self::Input, config: &Self::Config) -> Answer {
+        part2(*player1_start, *player2_start, config).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_with};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day21>(EXAMPLE, 1), Ok(Answer::Int(739785)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day21>(EXAMPLE, 2), Ok(Answer::Int(444356092776315)));
+    }
+
+    #[test]
+    fn starts_on_the_configured_track() {
+        let input = "Player 1 starting position: 15\nPlayer 2 starting position: 8\n";
+        let long = Day21Config {
+            track_length: 20,
+            ..Day21Config::default()
+        };
+        assert!(solve_with::<Day21>(input, 1, &long).is_ok());
+        let error = solve::<Day21>(input, 1).unwrap_err();
+        assert_eq!(error.expected, "a position from 1 to 10");
+        assert_eq!(error.found, "'15'");
+
+        let short = Day21Config {
+            track_length: 5,
+            ..Day21Config::default()
+        };
+        let error = solve_with::<Day21>(input, 1, &short).unwrap_err();
+        assert_eq!(error.expected, "a position from 1 to 5");
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+// A cuboid as (start, end) along x, y and z, each inside -limit..=limit
+fn cuboid(rng: &mut Rng, limit: i64, min_len: i64, max_len: i64) -> [(i64, i64); 3] {
+    [0; 3].map(|_| {
+        let len = rng.range(min_len..=max_len);
+        let start = rng.range(-limit..=limit - len);
+        (start, start + len)
+    })
+}
+
+// `size` is the number of reboot steps. The first 20 stay inside the initialization area,
+// like the puzzle's, and the rest are spread much further out without touching it.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let steps = (0..size.unwrap_or(420)).map(|i| {
+        let state = if rng.chance(0.6) { "on" } else { "off" };
+        let ranges = if i < 20 {
+            cuboid(rng, 50, 5, 50)
+        } else {
+            loop {
+                let ranges = cuboid(rng, 100_000, 5_000, 60_000);
+                if ranges.iter().any(|&(start, end)| start > 50 || end < -50) {
+                    break ranges;
+                }
+            }
+        };
+        let [x, y, z] = ranges;
+        format!(
+            "{} x={}..{},y={}..{},z={}..{}",
+            state, x.0, x.1, y.0, y.1, z.0, z.1
+        )
+    });
+    Generated::new(lines(steps))
+}
@@ -0,0 +1,320 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use geometry::{Bounds3, Point3};
+use regex::Regex;
+
+fn create_tree(steps: &[Step]) -> GridNode {
+    let mut bounds = steps[0].bounds;
+    for step in steps {
+        bounds = bounds.union(step.bounds);
+    }
+
+    let mut grid_root = GridNode::new(bounds, false);
+    for step in steps {
+        grid_root.run_step(step);
+    }
+
+    grid_root
+}
+
+fn part1(grid_root: &GridNode) -> usize {
+    let region = Bounds3::new(Point3::new(-50, -50, -50), Point3::new(50, 50, 50));
+    grid_root.num_on_within(region)
+}
+
+fn part2(grid_root: &GridNode) -> usize {
+    grid_root.num_on()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Step {
+    on: bool,
+    bounds: Bounds3,
+}
+
+#[derive(Debug, Clone)]
+pub struct GridNode {
+    bounds: Bounds3,
+    is_on: bool,
+    children: Option<Vec<GridNode>>,
+}
+
+impl GridNode {
+    fn new(bounds: Bounds3, is_on: bool) -> GridNode {
+        GridNode {
+            bounds,
+            is_on,
+            children: None,
+        }
+    }
+
+    // Splits the node in two along `axis`, with the second half starting at `loc`
+    fn create_children(&mut self, loc: i64, axis: usize) {
+        let mut child0_bounds = self.bounds;
+        let mut child1_bounds = self.bounds;
+        child0_bounds.max[axis] = loc - 1;
+        child1_bounds.min[axis] = loc;
+
+        self.children = Some(vec![
+            GridNode::new(child0_bounds, self.is_on),
+            GridNode::new(child1_bounds, self.is_on),
+        ]);
+    }
+
+    fn run_step(&mut self, step: &Step) {
+        if !self.bounds.intersects(step.bounds) {
+            return;
+        }
+
+        if self.children.is_none() {
+            if self.is_on == step.on {
+                return;
+            }
+
+            // Split off whatever sticks out of the step's bounds, one side at a time, until
+            // a node lies entirely inside them
+            let (min, max) = (self.bounds.min, self.bounds.max);
+            let split = (0..3).find_map(|axis| {
+                if min[axis] < step.bounds.min[axis] && max[axis] >= step.bounds.min[axis] {
+                    Some((step.bounds.min[axis], axis))
+                } else if min[axis] <= step.bounds.max[axis] && max[axis] > step.bounds.max[axis] {
+                    Some((step.bounds.max[axis] + 1, axis))
+                } else {
+                    None
+                }
+            });
+            match split {
+                Some((loc, axis)) => self.create_children(loc, axis),
+                None => {
+                    self.is_on = step.on;
+                    return;
+                }
+            }
+        } else if step.bounds.contains(self.bounds) {
+            self.is_on = step.on;
+            self.children = None;
+            return;
+        }
+
+        if let Some(ref mut children) = self.children {
+            for child in children {
+                child.run_step(step);
+            }
+        }
+    }
+
+    fn num_on(&self) -> usize {
+        if let Some(ref children) = self.children {
+            children.iter().map(|child| child.num_on()).sum()
+        } else if self.is_on {
+            self.volume() as usize
+        } else {
+            0
+        }
+    }
+
+    fn num_on_within(&self, bounds: Bounds3) -> usize {
+        let limited_bounds = match self.bounds.intersection(bounds) {
+            Some(limited_bounds) => limited_bounds,
+            None => return 0,
+        };
+
+        if let Some(ref children) = self.children {
+            children
+                .iter()
+                .map(|child| child.num_on_within(bounds))
+                .sum()
+        } else if self.is_on {
+            limited_bounds.volume() as usize
+        } else {
+            0
+        }
+    }
+
+    fn volume(&self) -> i64 {
+        self.bounds.volume()
+    }
+}
+
+// Cuboids stay small enough that the volume of the box holding them all fits in an i64
+const MAX_COORD: i64 = 1_000_000;
+
+fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    let mut steps: Vec<Step> = Vec::new();
+    let re =
+        Regex::new(r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
+            .unwrap();
+    for line in input.lines().map(|line| line.trim()) {
+        if line.is_empty() {
+            continue;
+        }
+        let caps = re
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, "'on|off x=A..B,y=C..D,z=E..F'"))?;
+        let number = |text: &str| match parse::number::<i64>(text)? {
+            n if (-MAX_COORD..=MAX_COORD).contains(&n) => Ok(n),
+            _ => {
+                let expected = format!("a coordinate from -{0} to {0}", MAX_COORD);
+                Err(ParseError::new(text, expected))
+            }
+        };
+        let on = &caps[1] == "on";
+        let mut min = Point3::origin();
+        let mut max = Point3::origin();
+        for axis in 0..3 {
+            let (low, high) = (
+                caps.get(2 + axis * 2).unwrap(),
+                caps.get(3 + axis * 2).unwrap(),
+            );
+            min[axis] = number(low.as_str())?;
+            max[axis] = number(high.as_str())?;
+            if min[axis] > max[axis] {
+                let range = &line[low.start()..high.end()];
+                return Err(ParseError::new(range, "a range from low to high"));
+            }
+        }
+        steps.push(Step {
+            on,
+            bounds: Bounds3::new(min, max),
+        });
+    }
+
+    if steps.is_empty() {
+        return Err(ParseError::new(input, "a reboot step"));
+    }
+    Ok(steps)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Step>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] =
+        aoc_common::examples!("example.txt", "simple_example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(&create_tree(steps)).into()
+    }
+
+    fn part2(steps: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(&create_tree(steps)).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const SIMPLE_EXAMPLE: &str = include_str!("../simple_example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day22>(EXAMPLE, 1), Ok(Answer::Int(474140)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve::<Day22>(EXAMPLE, 2),
+            Ok(Answer::Int(2758514936282235))
+        );
+    }
+
+    #[test]
+    fn rejects_unusable_cuboids() {
+        let error = solve::<Day22>("on x=-5..9999999999,y=0..1,z=0..1", 2).unwrap_err();
+        assert_eq!(error.expected, "a coordinate from -1000000 to 1000000");
+        assert_eq!(error.found, "'9999999999'");
+        let error = solve::<Day22>("on x=0..1,y=3..2,z=0..1", 2).unwrap_err();
+        assert_eq!(error.expected, "a range from low to high");
+        assert_eq!(error.found, "'3..2'");
+        assert!(solve::<Day22>("on x=0..1,y=0..1,z=-9223372036854775808..1", 2).is_err());
+        assert!(solve::<Day22>("\n", 2).is_err());
+
+        let widest = "on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000";
+        assert_eq!(
+            solve::<Day22>(widest, 2),
+            Ok(Answer::Int(2000001i128.pow(3)))
+        );
+    }
+
+    // simple_example.txt isn't from the puzzle text, so rather than pinning our own answer
+    // it's checked against switching every cube one at a time
+    #[test]
+    fn simple_example_matches_cube_by_cube() {
+        let cubes = naive_cubes(&parse_input(SIMPLE_EXAMPLE).unwrap()).len();
+        assert_eq!(
+            solve::<Day22>(SIMPLE_EXAMPLE, 1),
+            Ok(Answer::Int(cubes as i128))
+        );
+        assert_eq!(
+            solve::<Day22>(SIMPLE_EXAMPLE, 2),
+            Ok(Answer::Int(cubes as i128))
+        );
+    }
+
+    // Cuboids small enough to switch every cube on and off one at a time
+    fn small_bounds() -> impl Strategy<Value = Bounds3> {
+        prop::array::uniform3((-6i64..6, 0i64..5)).prop_map(|axes| {
+            let min = Point3::new(axes[0].0, axes[1].0, axes[2].0);
+            let max = Point3::new(
+                axes[0].0 + axes[0].1,
+                axes[1].0 + axes[1].1,
+                axes[2].0 + axes[2].1,
+            );
+            Bounds3::new(min, max)
+        })
+    }
+
+    fn naive_cubes(steps: &[Step]) -> HashSet<Point3> {
+        let mut cubes = HashSet::new();
+        for step in steps {
+            let (min, max) = (step.bounds.min, step.bounds.max);
+            for x in min.x()..=max.x() {
+                for y in min.y()..=max.y() {
+                    for z in min.z()..=max.z() {
+                        if step.on {
+                            cubes.insert(Point3::new(x, y, z));
+                        } else {
+                            cubes.remove(&Point3::new(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+        cubes
+    }
+
+    proptest! {
+        #[test]
+        fn matches_cube_by_cube(
+            steps in prop::collection::vec(
+                (any::<bool>(), small_bounds()).prop_map(|(on, bounds)| Step { on, bounds }),
+                1..10,
+            ),
+            region in small_bounds(),
+        ) {
+            let cubes = naive_cubes(&steps);
+            let tree = create_tree(&steps);
+            prop_assert_eq!(part1(&tree), cubes.len());
+            prop_assert_eq!(part2(&tree), cubes.len());
+            let within = cubes.iter().filter(|&&cube| region.contains_point(cube)).count();
+            prop_assert_eq!(tree.num_on_within(region), within);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_common::generate::{Generated, Rng};
+
+// The burrow is always the same shape, so `size` is unused
+pub fn generate(rng: &mut Rng, _size: Option<usize>) -> Generated {
+    let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+    rng.shuffle(&mut amphipods);
+    let row = |start: usize| {
+        let cells: Vec<String> = amphipods[start..start + 4]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        cells.join("#")
+    };
+
+    Generated::new(format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(0),
+        row(4)
+    ))
+}
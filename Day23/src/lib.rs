@@ -0,0 +1,431 @@
+mod generate;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+#[allow(unused)]
+use itertools::Itertools;
+#[allow(unused)]
+use regex::Regex;
+use search::dijkstra;
+use std::fmt;
+use tracing::trace;
+
+fn part1(input_map: &Map) -> i64 {
+    input_map.lowest_cost()
+}
+
+fn part2(input_map: &Map) -> i64 {
+    let unfolded_map = input_map.unfolded();
+    trace!("unfolded burrow:\n{}", unfolded_map);
+
+    unfolded_map.lowest_cost()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Move {
+    from_location: usize,
+    from_index: usize,
+    to_location: usize,
+    to_index: usize,
+    cost: i64,
+}
+
+impl Move {
+    fn new(
+        from_location: usize,
+        from_index: usize,
+        to_location: usize,
+        to_index: usize,
+        piece: usize,
+    ) -> Move {
+        let mut m = Move {
+            from_location,
+            from_index,
+            to_location,
+            to_index,
+            cost: 0,
+        };
+
+        m.cost = m.cost(piece);
+        m
+    }
+
+    fn dist(&self) -> i64 {
+        let mut dist: i64 = 0;
+        if self.from_location < OPEN_SPACE_INDEX {
+            dist += (self.from_index as i64) + 1;
+        }
+        if self.to_location < OPEN_SPACE_INDEX {
+            dist += (self.to_index as i64) + 1;
+        }
+
+        let from_open_index = if self.from_location < OPEN_SPACE_INDEX {
+            ROOM_ENTRANCES[self.from_location]
+        } else {
+            self.from_index
+        };
+        let to_open_index = if self.to_location < OPEN_SPACE_INDEX {
+            ROOM_ENTRANCES[self.to_location]
+        } else {
+            self.to_index
+        };
+
+        dist += (to_open_index as i64 - from_open_index as i64).abs();
+
+        dist
+    }
+
+    fn cost(&self, piece: usize) -> i64 {
+        self.dist() * ENERGY_COSTS[piece - 1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Map {
+    open_space: [usize; 11],
+    rooms: Vec<Vec<usize>>,
+}
+
+impl Map {
+    fn new() -> Map {
+        Map {
+            open_space: [0; 11],
+            rooms: vec![vec![0; 2]; 4],
+        }
+    }
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let mut map = Map::new();
+
+        let mut lines = input.lines().skip(2);
+        let re = Regex::new(r"^\s*#+([A-D])#([A-D])#([A-D])#([A-D])#+\s*$").unwrap();
+        for depth in 0..2 {
+            let line = parse::next(&mut lines, input.trim_end(), "a row of amphipods")?;
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::new(line.trim(), "'#A#B#C#D#'"))?;
+            for room in 0..4 {
+                let amphipod = caps[room + 1].parse::<char>().unwrap();
+                map.rooms[room][depth] = amphipod as usize - 'A' as usize + 1;
+            }
+        }
+
+        // The burrow can only be organized if every room has exactly one pair of each type
+        for amphipod in 1..=4 {
+            let count = map
+                .rooms
+                .iter()
+                .flatten()
+                .filter(|&&a| a == amphipod)
+                .count();
+            if count != 2 {
+                return Err(ParseError::after(
+                    input.trim_end(),
+                    "two amphipods of each type",
+                ));
+            }
+        }
+
+        Ok(map)
+    }
+
+    // #D#C#B#A#
+    // #D#B#A#C#
+    fn unfolded(&self) -> Map {
+        let mut map = Map::new();
+        map.open_space = self.open_space;
+        map.rooms = Vec::new();
+        map.rooms
+            .push(vec![self.rooms[0][0], 4, 4, self.rooms[0][1]]);
+        map.rooms
+            .push(vec![self.rooms[1][0], 3, 2, self.rooms[1][1]]);
+        map.rooms
+            .push(vec![self.rooms[2][0], 2, 1, self.rooms[2][1]]);
+        map.rooms
+            .push(vec![self.rooms[3][0], 1, 3, self.rooms[3][1]]);
+
+        map
+    }
+
+    // Cheapest way to organize the burrow, or i64::MAX if it can't be done
+    fn lowest_cost(&self) -> i64 {
+        let successors = |map: &Map| {
+            map.get_moves()
+                .into_iter()
+                .map(|m| (map.execute_move(&m), m.cost))
+                .collect::<Vec<_>>()
+        };
+
+        match dijkstra(self.clone(), successors, Map::is_complete) {
+            Some((path, cost)) => {
+                Map::animate(&path, cost);
+                cost
+            }
+            None => i64::MAX,
+        }
+    }
+
+    // Replays the cheapest way to organize the burrow, one move per frame
+    fn animate(path: &[Map], cost: i64) {
+        for (i, map) in path.iter().enumerate() {
+            animate::frame(|| {
+                let caption = format!("Move {} of {}, {} energy in all", i, path.len() - 1, cost);
+                Frame::from_text(caption, &map.to_string(), |c| match c {
+                    'A' => Some(Color::Yellow),
+                    'B' => Some(Color::Cyan),
+                    'C' => Some(Color::Red),
+                    'D' => Some(Color::Magenta),
+                    '#' => Some(Color::Gray),
+                    _ => None,
+                })
+            });
+        }
+    }
+
+    fn get_moves(&self) -> Vec<Move> {
+        let mut moves: Vec<Move> = Vec::new();
+        for i in 0..11 {
+            let piece: usize = self.open_space[i];
+            if piece == 0 {
+                continue;
+            }
+            let home_index = piece - 1;
+            for home_slot in (0..self.rooms[home_index].len()).rev() {
+                let existing_piece = self.rooms[home_index][home_slot];
+                if existing_piece == 0 {
+                    let m = Move::new(OPEN_SPACE_INDEX, i, home_index, home_slot, piece);
+                    if self.is_move_valid(&m) {
+                        moves.push(m);
+                        return moves;
+                    }
+                } else if existing_piece != piece {
+                    break;
+                }
+            }
+        }
+
+        for room_index in 0..self.rooms.len() {
+            if !self.has_incorrect_piece(room_index) {
+                continue;
+            }
+
+            let (move_piece, move_piece_slot) = self.top_piece_and_slot(room_index);
+            if move_piece != 0 && self.can_go_home(move_piece) {
+                let home_index = move_piece - 1;
+                let home_slot = self.open_slot(home_index);
+                let m = Move::new(
+                    room_index,
+                    move_piece_slot,
+                    home_index,
+                    home_slot,
+                    move_piece,
+                );
+                if self.is_move_valid(&m) {
+                    moves.push(m);
+                    return moves;
+                }
+            }
+        }
+
+        for room_index in 0..self.rooms.len() {
+            if !self.has_incorrect_piece(room_index) {
+                continue;
+            }
+
+            let (move_piece, move_piece_slot) = self.top_piece_and_slot(room_index);
+            for i in 0..11 {
+                if i == 2 || i == 4 || i == 6 || i == 8 {
+                    continue;
+                }
+
+                let m = Move::new(room_index, move_piece_slot, OPEN_SPACE_INDEX, i, move_piece);
+                if self.is_move_valid(&m) {
+                    moves.push(m);
+                }
+            }
+        }
+        moves
+    }
+
+    fn can_go_home(&self, piece: usize) -> bool {
+        !self.has_incorrect_piece(piece - 1)
+    }
+
+    fn open_slot(&self, room_index: usize) -> usize {
+        for i in (0..self.rooms[room_index].len()).rev() {
+            if self.rooms[room_index][i] == 0 {
+                return i;
+            }
+        }
+        panic!("No open slot in room {}", room_index);
+    }
+
+    fn top_piece_and_slot(&self, room_index: usize) -> (usize, usize) {
+        for slot in 0..self.rooms[room_index].len() {
+            let cur_piece = self.rooms[room_index][slot];
+            if cur_piece != 0 {
+                return (cur_piece, slot);
+            }
+        }
+        (0, 0)
+    }
+
+    fn is_move_valid(&self, m: &Move) -> bool {
+        let (start, dest) = if m.from_location == OPEN_SPACE_INDEX {
+            (m.from_index, ROOM_ENTRANCES[m.to_location])
+        } else if m.to_location == OPEN_SPACE_INDEX {
+            (ROOM_ENTRANCES[m.from_location], m.to_index)
+        } else {
+            (
+                ROOM_ENTRANCES[m.from_location],
+                ROOM_ENTRANCES[m.to_location],
+            )
+        };
+
+        if start < dest {
+            for i in start + 1..=dest {
+                if self.open_space[i] != 0 {
+                    return false;
+                }
+            }
+        } else {
+            for i in dest..start {
+                if self.open_space[i] != 0 {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn execute_move(&self, m: &Move) -> Map {
+        let mut map = self.clone();
+        let piece;
+        if m.from_location < OPEN_SPACE_INDEX {
+            piece = map.rooms[m.from_location][m.from_index];
+            map.rooms[m.from_location][m.from_index] = 0;
+        } else {
+            piece = map.open_space[m.from_index];
+            map.open_space[m.from_index] = 0;
+        }
+
+        if m.to_location < OPEN_SPACE_INDEX {
+            map.rooms[m.to_location][m.to_index] = piece;
+        } else {
+            map.open_space[m.to_index] = piece;
+        }
+
+        map
+    }
+
+    fn has_incorrect_piece(&self, room_index: usize) -> bool {
+        let correct_piece = room_index + 1;
+        let room = &self.rooms[room_index];
+        for &cur_piece in room {
+            if cur_piece != 0 && cur_piece != correct_piece {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_complete(&self) -> bool {
+        if self.open_space.iter().any(|&piece| piece != 0) {
+            return false;
+        }
+
+        for room_index in 0..self.rooms.len() {
+            if self.has_incorrect_piece(room_index) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn piece_name(piece: usize) -> char {
+        if piece == 0 {
+            return '.';
+        }
+        (b'A' + (piece - 1) as u8) as char
+    }
+}
+
+// Drawn the way the puzzle draws the burrow
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "#############")?;
+        write!(f, "#")?;
+        for &piece in &self.open_space {
+            write!(f, "{}", Self::piece_name(piece))?;
+        }
+        writeln!(f, "#")?;
+        writeln!(
+            f,
+            "###{}#{}#{}#{}###",
+            Self::piece_name(self.rooms[0][0]),
+            Self::piece_name(self.rooms[1][0]),
+            Self::piece_name(self.rooms[2][0]),
+            Self::piece_name(self.rooms[3][0])
+        )?;
+        for i in 1..self.rooms[0].len() {
+            writeln!(
+                f,
+                "  #{}#{}#{}#{}#",
+                Self::piece_name(self.rooms[0][i]),
+                Self::piece_name(self.rooms[1][i]),
+                Self::piece_name(self.rooms[2][i]),
+                Self::piece_name(self.rooms[3][i])
+            )?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+const ENERGY_COSTS: [i64; 4] = [1, 10, 100, 1000];
+const ROOM_ENTRANCES: [usize; 4] = [2, 4, 6, 8];
+const OPEN_SPACE_INDEX: usize = 4;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(map: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(map).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day23>(EXAMPLE, 1), Ok(Answer::Int(12521)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day23>(EXAMPLE, 2), Ok(Answer::Int(44169)));
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_common::generate::{Generated, Rng};
+
+// One block of MONAD, which reads a digit and either pushes it onto z (when `div` is 1) or
+// pops the matching digit off and checks it
+fn block(div: i64, offset_x: i64, offset_y: i64) -> String {
+    format!(
+        "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\n\
+         add y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+        div, offset_x, offset_y
+    )
+}
+
+// The program is always 14 digits long, so `size` is unused. Pushes and pops are paired up
+// at random, and each pair requires the popped digit to be the pushed one plus some
+// difference, which is what makes both the largest and smallest serial known up front.
+pub fn generate(rng: &mut Rng, _size: Option<usize>) -> Generated {
+    let mut blocks = Vec::new();
+    let mut largest = [0i64; 14];
+    let mut smallest = [0i64; 14];
+    let mut stack: Vec<(usize, i64)> = Vec::new();
+    let mut pushes_left = 7;
+    for digit in 0..14 {
+        if pushes_left > 0 && (stack.is_empty() || rng.chance(0.5)) {
+            let offset_y = rng.range(1..=16);
+            blocks.push(block(1, rng.range(10..=16), offset_y));
+            stack.push((digit, offset_y));
+            pushes_left -= 1;
+            continue;
+        }
+
+        let (pushed, offset_y) = stack.pop().unwrap();
+        let difference = rng.range(-8..=8);
+        blocks.push(block(26, difference - offset_y, rng.range(1..=16)));
+        largest[pushed] = 9 - difference.max(0);
+        largest[digit] = 9 + difference.min(0);
+        smallest[pushed] = 1 - difference.min(0);
+        smallest[digit] = 1 + difference.max(0);
+    }
+
+    let serial = |digits: [i64; 14]| digits.iter().fold(0, |acc, digit| acc * 10 + digit);
+    Generated::new(blocks.concat())
+        .with_answer(1, serial(largest))
+        .with_answer(2, serial(smallest))
+}
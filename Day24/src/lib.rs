@@ -0,0 +1,232 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use regex::Regex;
+
+fn part1(div_z: &[i64], offsets_x: &[i64], offsets_y: &[i64]) -> i64 {
+    find_serial(div_z, offsets_x, offsets_y, false)
+}
+
+fn part2(div_z: &[i64], offsets_x: &[i64], offsets_y: &[i64]) -> i64 {
+    find_serial(div_z, offsets_x, offsets_y, true)
+}
+
+fn find_serial(div_z: &[i64], offsets_x: &[i64], offsets_y: &[i64], increment: bool) -> i64 {
+    let mut serial = first_serial(div_z, offsets_x, offsets_y, increment);
+    loop {
+        let result = simulate_program(&serial, div_z, offsets_x, offsets_y);
+        if result == 0 {
+            return serial
+                .iter()
+                .map(|x| (*x as u8 + b'0') as char)
+                .collect::<String>()
+                .parse::<i64>()
+                .unwrap();
+        }
+
+        let new_serial = next_serial(&serial, div_z, offsets_x, offsets_y, increment);
+        if new_serial.is_none() {
+            break;
+        }
+        serial = new_serial.unwrap();
+    }
+
+    0
+}
+
+fn first_serial(div_z: &[i64], offsets_x: &[i64], offsets_y: &[i64], increment: bool) -> [i64; 14] {
+    let mut serial = [0i64; 14];
+    let default_digit = if increment { 1 } else { 9 };
+    serial[0] = default_digit;
+    let mut z = serial[0] + offsets_y[0];
+    for i in 1..14 {
+        serial[i] = default_digit;
+
+        let z_div = div_z[i];
+        let x_offset = offsets_x[i];
+        let y_offset = offsets_y[i];
+
+        let check = (z % 26) + x_offset;
+        z /= z_div;
+
+        if (1..=9).contains(&check) {
+            serial[i] = check;
+        } else {
+            z = (z * 26) + serial[i] + y_offset;
+        }
+    }
+    serial
+}
+
+fn next_serial(
+    serial: &[i64],
+    div_z: &[i64],
+    offsets_x: &[i64],
+    offsets_y: &[i64],
+    increment: bool,
+) -> Option<[i64; 14]> {
+    let mut next_serial = [0i64; 14];
+
+    let mut start_change_index = 0;
+    {
+        let mut z: i64 = 0;
+        for i in 0..14 {
+            let inp = serial[i];
+            let z_div = div_z[i];
+            let x_offset = offsets_x[i];
+            let y_offset = offsets_y[i];
+
+            let check = (z % 26) + x_offset;
+            z /= z_div;
+            if check != inp {
+                if div_z[i] == 26 {
+                    start_change_index = i - 1;
+                    break;
+                }
+
+                z = (z * 26) + inp + y_offset;
+            }
+        }
+    }
+
+    for change_index in (0..=start_change_index).rev() {
+        if increment && serial[change_index] == 9 || !increment && serial[change_index] == 1 {
+            continue;
+        }
+
+        let mut z: i64 = 0;
+        for i in 0..change_index {
+            let inp = serial[i];
+            next_serial[i] = inp;
+
+            let z_div = div_z[i];
+            let x_offset = offsets_x[i];
+            let y_offset = offsets_y[i];
+
+            let check = (z % 26) + x_offset;
+            z /= z_div;
+
+            if check != inp {
+                z = (z * 26) + inp + y_offset;
+            }
+        }
+
+        if increment {
+            next_serial[change_index] = serial[change_index] + 1;
+        } else {
+            next_serial[change_index] = serial[change_index] - 1;
+        }
+
+        let check = (z % 26) + offsets_x[change_index];
+        z /= div_z[change_index];
+
+        if check != next_serial[change_index] {
+            z = (z * 26) + next_serial[change_index] + offsets_y[change_index];
+        }
+
+        for i in change_index + 1..14 {
+            let z_div = div_z[i];
+            let x_offset = offsets_x[i];
+            let y_offset = offsets_y[i];
+
+            let check = (z % 26) + x_offset;
+            z /= z_div;
+
+            if (1..=9).contains(&check) {
+                next_serial[i] = check;
+            } else {
+                next_serial[i] = if increment { 1 } else { 9 };
+                z = (z * 26) + next_serial[i] + y_offset;
+            }
+        }
+        return Some(next_serial);
+    }
+
+    None
+}
+
+fn simulate_program(serial: &[i64], div_z: &[i64], offsets_x: &[i64], offsets_y: &[i64]) -> i64 {
+    let mut z: i64 = 0;
+    for i in 0..14 {
+        let inp = serial[i];
+        let z_div = div_z[i];
+        let x_offset = offsets_x[i];
+        let y_offset = offsets_y[i];
+
+        let check = (z % 26) + x_offset;
+        z /= z_div;
+        if check != inp {
+            z = (z * 26) + inp + y_offset;
+        }
+    }
+    z
+}
+
+fn parse_input(input: &str) -> Result<[i64; 42], ParseError> {
+    let re = Regex::new(r"... [xyz] (-?\d+)").unwrap();
+
+    let mut result = [0; 42];
+    let lines: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+    // The program is 14 blocks of 18 instructions, one block per input digit
+    if lines.len() < 14 * 18 {
+        return Err(ParseError::after(
+            input.trim_end(),
+            "14 blocks of 18 instructions",
+        ));
+    }
+    for (cur_line, line) in lines.iter().enumerate().take(14 * 18) {
+        let segment = cur_line / 18;
+        let offset = cur_line % 18;
+        let index = match offset {
+            4 => segment,
+            5 => segment + 14,
+            15 => segment + 28,
+            _ => continue,
+        };
+        let caps = re
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, "an instruction with a constant"))?;
+        result[index] = parse::number(caps.get(1).unwrap().as_str())?;
+    }
+    Ok(result)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = [i64; 42];
+    type Config = ();
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(offsets: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(&offsets[0..14], &offsets[14..28], &offsets[28..42]).into()
+    }
+
+    fn part2(offsets: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(&offsets[0..14], &offsets[14..28], &offsets[28..42]).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..10 {
+            let generated = Day24::generate(&mut Rng::new(seed), None);
+            for (part, answer) in &generated.answers {
+                assert_eq!(solve::<Day24>(&generated.input, *part).as_ref(), Ok(answer));
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the height of the sea floor map, which is a little wider than it is tall
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let height = size.unwrap_or(137);
+    let width = height + 2;
+    let mut input = String::new();
+    for _ in 0..height {
+        input.extend((0..width).map(|_| match rng.below(4) {
+            0 => '>',
+            1 => 'v',
+            _ => '.',
+        }));
+        input.push('\n');
+    }
+    Generated::new(input)
+}
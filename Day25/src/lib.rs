@@ -0,0 +1,39 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+#[allow(unused)]
+use itertools::Itertools;
+#[allow(unused)]
+use regex::Regex;
+
+fn part1(_input: &str) -> usize {
+    0
+}
+
+fn part2(_input: &str) -> usize {
+    0
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = String;
+    type Config = ();
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
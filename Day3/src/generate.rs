@@ -0,0 +1,24 @@
+use aoc_common::generate::{lines, Generated, Rng};
+use std::collections::HashSet;
+
+// `size` is the number of report lines. The lines are all different, since the ratings in
+// part 2 are narrowed down to a single one, and widen past 12 bits when there are too many.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(1000);
+    let mut width = 12;
+    while (1usize << width) < size * 2 {
+        width += 1;
+    }
+
+    let mut seen = HashSet::new();
+    let mut report = Vec::new();
+    while report.len() < size {
+        let line: String = (0..width)
+            .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        if seen.insert(line.clone()) {
+            report.push(line);
+        }
+    }
+    Generated::new(lines(report))
+}
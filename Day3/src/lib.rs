@@ -0,0 +1,74 @@
+mod generate;
+pub mod report;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+use report::Report;
+
+fn part1(report: &Report) -> Answer {
+    // Gamma has each bit set that more than half the lines have, and epsilon is the rest
+    let lines = report.len();
+    let mut gamma = vec![0u64; report.width().div_ceil(64).max(1)];
+    let mut epsilon = gamma.clone();
+    for (pos, count) in report.column_counts().into_iter().enumerate() {
+        let rate = if count * 2 > lines {
+            &mut gamma
+        } else {
+            &mut epsilon
+        };
+        rate[pos / 64] |= 1 << (63 - pos % 64);
+    }
+
+    // Answer is gamma multiplied by epsilon
+    report::product(&report.value(&gamma), &report.value(&epsilon))
+}
+
+fn part2(report: &Report) -> Answer {
+    let oxygen_rating = report.value(report.rating(true));
+    let co2_rating = report.value(report.rating(false));
+
+    // Answer is the product of the ratings
+    report::product(&oxygen_rating, &co2_rating)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        Report::parse(input)
+    }
+
+    fn part1(report: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(report)
+    }
+
+    fn part2(report: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(report)
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day3>(EXAMPLE, 1), Ok(Answer::Int(198)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day3>(EXAMPLE, 2), Ok(Answer::Int(230)));
+    }
+}
@@ -0,0 +1,274 @@
+use aoc_common::{parse, Answer, ParseError};
+
+// A diagnostic report with each line packed into 64-bit words, first bit in the top bit of
+// the first word and any unused bits of the last word zero. Comparing two lines' words then
+// compares them as binary numbers, so the lines are kept sorted, which lets part 2 narrow
+// them down by binary searching instead of filtering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    width: usize,
+    words: usize,
+    rows: Vec<u64>,
+}
+
+impl Report {
+    pub fn parse(input: &str) -> Result<Report, ParseError> {
+        let mut width = None;
+        let mut rows = Vec::new();
+        for line in input.lines().map(|line| line.trim()) {
+            if let Some(i) = line.find(|c| c != '0' && c != '1') {
+                return Err(ParseError::new(parse::char_at(line, i), "'0' or '1'"));
+            }
+            let width = *width.get_or_insert(line.len());
+            if line.len() != width {
+                return Err(ParseError::new(line, format!("{} bits", width)));
+            }
+
+            let start = rows.len();
+            rows.resize(start + width.div_ceil(64).max(1), 0);
+            for (pos, bit) in line.bytes().enumerate() {
+                if bit == b'1' {
+                    rows[start + pos / 64] |= 1 << (63 - pos % 64);
+                }
+            }
+        }
+
+        let width = match width {
+            Some(width) => width,
+            None => return Err(ParseError::new(input, "a diagnostic report")),
+        };
+        let words = width.div_ceil(64).max(1);
+        let mut sorted: Vec<&[u64]> = rows.chunks(words).collect();
+        sorted.sort_unstable();
+        Ok(Report {
+            width,
+            words,
+            rows: sorted.concat(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len() / self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn row(&self, i: usize) -> &[u64] {
+        &self.rows[i * self.words..(i + 1) * self.words]
+    }
+
+    fn bit(&self, i: usize, pos: usize) -> bool {
+        self.rows[i * self.words + pos / 64] >> (63 - pos % 64) & 1 == 1
+    }
+
+    // How many lines have each bit set. Each word of counts is kept as bit planes, plane k
+    // holding bit k of the 64 counts, so adding a line is a ripple carry through the planes
+    // that counts 64 columns at once.
+    pub fn column_counts(&self) -> Vec<usize> {
+        let mut planes: Vec<Vec<u64>> = vec![Vec::new(); self.words];
+        for row in self.rows.chunks(self.words) {
+            for (planes, &word) in planes.iter_mut().zip(row) {
+                let mut carry = word;
+                for plane in planes.iter_mut() {
+                    if carry == 0 {
+                        break;
+                    }
+                    let sum = *plane ^ carry;
+                    carry &= *plane;
+                    *plane = sum;
+                }
+                if carry != 0 {
+                    planes.push(carry);
+                }
+            }
+        }
+
+        (0..self.width)
+            .map(|pos| {
+                let shift = 63 - pos % 64;
+                let planes = planes[pos / 64].iter().enumerate();
+                planes
+                    .map(|(k, plane)| ((plane >> shift & 1) as usize) << k)
+                    .sum()
+            })
+            .collect()
+    }
+
+    // The line left after keeping only the lines with the most (or least) common value of
+    // each bit in turn. Lines agreeing on every bit so far are a range of the sorted lines,
+    // with the ones that have the next bit set at the end of it.
+    pub fn rating(&self, keep_most_common: bool) -> &[u64] {
+        let (mut start, mut end) = (0, self.len());
+        for pos in 0..self.width {
+            if end - start <= 1 {
+                break;
+            }
+            let split = start + partition_point(end - start, |i| !self.bit(start + i, pos));
+            let (zeros, ones) = (split - start, end - split);
+            let keep_ones = if keep_most_common {
+                ones >= zeros
+            } else {
+                ones < zeros
+            };
+            // When every line has the same bit there's nothing to filter out
+            if keep_ones && ones > 0 || zeros == 0 {
+                start = split;
+            } else {
+                end = split;
+            }
+        }
+        self.row(start)
+    }
+
+    // The bits as a number, least significant word first
+    pub fn value(&self, row: &[u64]) -> Vec<u64> {
+        let pad = (self.words * 64 - self.width) as u32;
+        (0..self.words)
+            .rev()
+            .map(|i| {
+                let high = match i.checked_sub(1) {
+                    Some(prev) if pad > 0 => row[prev] << (64 - pad),
+                    _ => 0,
+                };
+                row[i].checked_shr(pad).unwrap_or(0) | high
+            })
+            .collect()
+    }
+}
+
+// The first index in 0..len for which `pred` is false, as with slice::partition_point
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+// The product of two numbers of any size, as given by Report::value. It's an Int when it
+// fits, as it always does for the puzzle's 12 bits, and written out in decimal otherwise.
+pub fn product(a: &[u64], b: &[u64]) -> Answer {
+    let mut limbs = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let total = limbs[i + j] as u128 + x as u128 * y as u128 + carry;
+            limbs[i + j] = total as u64;
+            carry = total >> 64;
+        }
+        limbs[i + b.len()] = carry as u64;
+    }
+    while limbs.len() > 1 && limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    if limbs.len() <= 2 {
+        let value = limbs[0] as u128 | (*limbs.get(1).unwrap_or(&0) as u128) << 64;
+        if let Ok(value) = i128::try_from(value) {
+            return Answer::Int(value);
+        }
+    }
+
+    // Divided down 10^19 at a time, the most that fits in a word
+    const CHUNK: u64 = 10_000_000_000_000_000_000;
+    let mut chunks = Vec::new();
+    while limbs.iter().any(|&limb| limb != 0) {
+        let mut remainder = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let current = remainder << 64 | *limb as u128;
+            *limb = (current / CHUNK as u128) as u64;
+            remainder = current % CHUNK as u128;
+        }
+        chunks.push(remainder as u64);
+    }
+    let mut digits = chunks.pop().unwrap_or(0).to_string();
+    for chunk in chunks.iter().rev() {
+        digits += &format!("{:019}", chunk);
+    }
+    Answer::Str(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::Rng;
+
+    // Counts and filters the lines as text, the slow way
+    fn naive_rating(lines: &[String], keep_most_common: bool) -> String {
+        let mut lines = lines.to_vec();
+        let mut pos = 0;
+        while lines.len() > 1 && pos < lines[0].len() {
+            let ones = lines.iter().filter(|line| &line[pos..=pos] == "1").count();
+            let zeros = lines.len() - ones;
+            let keep = match (keep_most_common, ones >= zeros, ones < zeros) {
+                (true, true, _) | (false, _, true) => "1",
+                _ => "0",
+            };
+            if lines.iter().any(|line| &line[pos..=pos] == keep) {
+                lines.retain(|line| &line[pos..=pos] == keep);
+            }
+            pos += 1;
+        }
+        lines[0].clone()
+    }
+
+    fn to_text(report: &Report, row: &[u64]) -> String {
+        (0..report.width())
+            .map(|pos| match row[pos / 64] >> (63 - pos % 64) & 1 {
+                1 => '1',
+                _ => '0',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wide_reports_match_naive() {
+        let mut rng = Rng::new(3);
+        for width in [1, 5, 64, 70, 130] {
+            let lines: Vec<String> = (0..200)
+                .map(|_| {
+                    (0..width)
+                        .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+                        .collect()
+                })
+                .collect();
+            let report = Report::parse(&lines.join("\n")).unwrap();
+
+            let counts: Vec<usize> = (0..width)
+                .map(|pos| lines.iter().filter(|line| &line[pos..=pos] == "1").count())
+                .collect();
+            assert_eq!(report.column_counts(), counts);
+            for keep_most_common in [true, false] {
+                assert_eq!(
+                    to_text(&report, report.rating(keep_most_common)),
+                    naive_rating(&lines, keep_most_common)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn products_of_any_size() {
+        let report = Report::parse(&format!("1{}1", "0".repeat(63))).unwrap();
+        let value = report.value(report.row(0));
+        assert_eq!(value, [1, 1]);
+        // (2^64 + 1)^2
+        assert_eq!(
+            product(&value, &value),
+            Answer::Str("340282366920938463500268095579187314689".to_string())
+        );
+        assert_eq!(product(&[6], &[7]), Answer::Int(42));
+        assert_eq!(product(&[0, 1], &[3]), Answer::Int(3 << 64));
+    }
+}
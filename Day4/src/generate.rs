@@ -0,0 +1,22 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the number of 5x5 boards. Every number from 0 to 99 is drawn, so every board wins
+// eventually.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut balls: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut balls);
+    let balls: Vec<String> = balls.iter().map(|ball| ball.to_string()).collect();
+    let mut input = balls.join(",") + "\n";
+
+    for _ in 0..size.unwrap_or(100) {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+    }
+    Generated::new(input)
+}
@@ -0,0 +1,266 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+aoc_common::config! {
+    pub struct Day4Config {
+        // Boards are board_size numbers square
+        board_size: usize = 5,
+    }
+    check = check_config;
+}
+
+// Far bigger than any bingo card, and small enough that board_size squared can't overflow
+const MAX_BOARD_SIZE: usize = 1000;
+
+fn check_config(config: &Day4Config) -> Result<(), String> {
+    if config.board_size == 0 {
+        return Err("board_size must be at least 1".to_string());
+    }
+    if config.board_size > MAX_BOARD_SIZE {
+        return Err(format!("board_size must be at most {}", MAX_BOARD_SIZE));
+    }
+    Ok(())
+}
+
+fn part1(balls: &[u32], boards: &mut [Board]) -> (u32, u64) {
+    for cur_ball in balls {
+        for board in boards.iter_mut() {
+            if board.mark_ball(*cur_ball) && board.is_solved() {
+                return (*cur_ball, board.sum_unset());
+            }
+        }
+    }
+
+    (0, 0)
+}
+
+fn part2(balls: &[u32], boards: &mut [Board]) -> (u32, u64) {
+    let total_boards = boards.len();
+    let mut board_finished: Vec<bool> = vec![false; boards.len()];
+    let mut num_finished = 0;
+
+    for cur_ball in balls {
+        for (i, board) in boards.iter_mut().enumerate() {
+            if board_finished[i] {
+                continue;
+            }
+
+            if board.mark_ball(*cur_ball) && board.is_solved() {
+                board_finished[i] = true;
+                num_finished += 1;
+                if num_finished == total_boards {
+                    return (*cur_ball, board.sum_unset());
+                }
+            }
+        }
+    }
+
+    (0, 0)
+}
+
+fn parse_first_line(line: &str) -> Result<Vec<u32>, ParseError> {
+    let mut result: Vec<u32> = Vec::new();
+    for s in line.split(",") {
+        result.push(parse::number(s)?);
+    }
+    Ok(result)
+}
+
+fn parse_board_line(line: &str, list: &mut Vec<u32>) -> Result<(), ParseError> {
+    for s in line.split(" ") {
+        let s = s.trim();
+        if !s.is_empty() {
+            list.push(parse::number(s)?);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    numbers: Vec<u32>,
+    is_called: Vec<bool>,
+    num_marked_balls: u32,
+}
+
+impl Board {
+    fn new(numbers: Vec<u32>) -> Board {
+        Board {
+            is_called: vec![false; numbers.len()],
+            numbers,
+            num_marked_balls: 0,
+        }
+    }
+
+    fn resetted(&self) -> Board {
+        Board {
+            is_called: vec![false; self.numbers.len()],
+            numbers: self.numbers.clone(),
+            num_marked_balls: 0,
+        }
+    }
+
+    fn ball_index(&self, ball: u32) -> usize {
+        match self.numbers.iter().position(|&b| b == ball) {
+            Some(i) => i,
+            None => self.numbers.len(),
+        }
+    }
+
+    fn mark_ball(&mut self, ball: u32) -> bool {
+        match self.ball_index(ball) {
+            i if i < self.numbers.len() => {
+                self.is_called[i] = true;
+                self.num_marked_balls += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_solved(&self) -> bool {
+        let width = (self.numbers.len() as f64).sqrt() as usize;
+        let height = self.numbers.len() / width;
+
+        if (self.num_marked_balls as usize) < width {
+            return false;
+        }
+
+        // Check each row
+        for i in 0..height {
+            let mut solved_count = 0;
+            for j in 0..width {
+                if self.is_called[i * width + j] {
+                    solved_count += 1;
+                }
+            }
+            if solved_count == width {
+                return true;
+            }
+        }
+
+        // Check each column
+        for j in 0..width {
+            let mut solved_count = 0;
+            for i in 0..height {
+                if self.is_called[i * width + j] {
+                    solved_count += 1;
+                }
+            }
+            if solved_count == height {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn sum_unset(&self) -> u64 {
+        let size = self.numbers.len();
+        let mut sum = 0;
+        for i in 0..size {
+            if !self.is_called[i] {
+                sum += self.numbers[i] as u64;
+            }
+        }
+        sum
+    }
+}
+
+fn reset_boards(boards: &Vec<Board>) -> Vec<Board> {
+    let mut result: Vec<Board> = Vec::new();
+    for board in boards {
+        result.push(board.resetted());
+    }
+
+    result
+}
+
+fn parse(input: &str, board_size: usize) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.is_empty() {
+        return Err(ParseError::new(input, "a list of balls"));
+    }
+
+    let balls = parse_first_line(lines[0])?;
+
+    let mut boards: Vec<Board> = Vec::new();
+    let mut current_board_values: Vec<u32> = Vec::new();
+    for line in &lines[1..] {
+        if line.trim().is_empty() {
+            continue;
+        }
+        parse_board_line(line, &mut current_board_values)?;
+        let board_len = board_size * board_size;
+        if current_board_values.len() > board_len {
+            let expected = format!("{} numbers per board row", board_size);
+            return Err(ParseError::new(line, expected));
+        }
+        if current_board_values.len() == board_len {
+            boards.push(Board::new(current_board_values));
+            current_board_values = Vec::new();
+        }
+    }
+
+    if !current_board_values.is_empty() {
+        return Err(ParseError::after(input.trim_end(), "the rest of the board"));
+    }
+    Ok((balls, boards))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<u32>, Vec<Board>);
+    type Config = Day4Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input, config.board_size)
+    }
+
+    fn part1((balls, boards): &Self::Input, _config: &Self::Config) -> Answer {
+        let (ball, num_unset) = part1(balls, &mut reset_boards(boards));
+        (ball as i128 * num_unset as i128).into()
+    }
+
+    fn part2((balls, boards): &Self::Input, _config: &Self::Config) -> Answer {
+        let (ball, num_unset) = part2(balls, &mut reset_boards(boards));
+        (ball as i128 * num_unset as i128).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_with};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day4>(EXAMPLE, 1), Ok(Answer::Int(4512)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day4>(EXAMPLE, 2), Ok(Answer::Int(1924)));
+    }
+
+    #[test]
+    fn scores_past_u32() {
+        let input = "4294967295,4294967294\n\n4294967295 4294967294\n4294967293 4294967292\n";
+        let config = Day4Config { board_size: 2 };
+        let score = 4294967294 * (4294967293 + 4294967292);
+        assert_eq!(
+            solve_with::<Day4>(input, 1, &config),
+            Ok(Answer::Int(score))
+        );
+    }
+}
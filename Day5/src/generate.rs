@@ -0,0 +1,25 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+const MAX_COORD: i64 = 989;
+
+// `size` is the number of vent lines, a mix of horizontal, vertical and 45 degree lines
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let vents = (0..size.unwrap_or(500)).map(|_| {
+        let (x1, y1) = (rng.range(0..=MAX_COORD), rng.range(0..=MAX_COORD));
+        let (x2, y2) = match rng.below(3) {
+            0 => (rng.range(0..=MAX_COORD), y1),
+            1 => (x1, rng.range(0..=MAX_COORD)),
+            _ => {
+                let dx = if rng.chance(0.5) { 1 } else { -1 };
+                let dy = if rng.chance(0.5) { 1 } else { -1 };
+                // As long as possible without leaving the area in either direction
+                let room_x = if dx > 0 { MAX_COORD - x1 } else { x1 };
+                let room_y = if dy > 0 { MAX_COORD - y1 } else { y1 };
+                let len = rng.range(0..=room_x.min(room_y));
+                (x1 + dx * len, y1 + dy * len)
+            }
+        };
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    });
+    Generated::new(lines(vents))
+}
@@ -0,0 +1,131 @@
+mod generate;
+
+pub mod line;
+
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+use geometry::Bounds2;
+use grid::Grid;
+use line::Line;
+
+fn new_grid_for(lines: &[Line]) -> Grid<u32> {
+    let corners = lines.iter().flat_map(|line| [line.start, line.end]);
+    match Bounds2::from_points(corners) {
+        Some(bounds) => Grid::new(bounds.max.x() as usize + 1, bounds.max.y() as usize + 1, 0),
+        None => Grid::new(0, 0, 0),
+    }
+}
+
+fn increment_line(grid: &mut Grid<u32>, line: &Line, allow_diagonals: bool) {
+    if !allow_diagonals && line.is_diagonal() {
+        return;
+    }
+
+    // Lines are horizontal, vertical or at 45 degrees, so each step moves at most one
+    // cell along each axis
+    let step = (line.end - line.start).signum();
+    let mut point = line.start;
+    loop {
+        grid[(point.x() as usize, point.y() as usize)] += 1;
+        if point == line.end {
+            break;
+        }
+        point += step;
+    }
+}
+
+// Cells covered once are yellow and the overlaps the puzzle counts are red
+fn animate_grid(grid: &Grid<u32>, caption: String) {
+    animate::frame(|| {
+        let text = grid.render(|&count| match count {
+            0 => '.',
+            1..=9 => char::from_digit(count, 10).unwrap(),
+            _ => '#',
+        });
+        Frame::from_text(caption, &text, |c| match c {
+            '.' => None,
+            '1' => Some(Color::Yellow),
+            _ => Some(Color::Red),
+        })
+    });
+}
+
+fn count_two_or_higher(grid: &Grid<u32>) -> u32 {
+    grid.cells().iter().filter(|&p| *p >= 2).count() as u32
+}
+
+fn part1(lines: &[Line]) -> u32 {
+    let mut grid = new_grid_for(lines);
+    for (i, line) in lines.iter().enumerate() {
+        increment_line(&mut grid, line, false);
+        animate_grid(&grid, format!("Part 1: line {} of {}", i + 1, lines.len()));
+    }
+
+    count_two_or_higher(&grid)
+}
+
+fn part2(lines: &[Line]) -> u32 {
+    let mut grid = new_grid_for(lines);
+    for (i, line) in lines.iter().enumerate() {
+        increment_line(&mut grid, line, true);
+        animate_grid(&grid, format!("Part 2: line {} of {}", i + 1, lines.len()));
+    }
+
+    count_two_or_higher(&grid)
+}
+
+fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    input.lines().map(Line::from_string).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(lines).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day5>(EXAMPLE, 1), Ok(Answer::Int(5)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day5>(EXAMPLE, 2), Ok(Answer::Int(12)));
+    }
+
+    #[test]
+    fn rejects_unsupported_lines() {
+        let error = solve::<Day5>("0,0 -> 2,2\n0,0 -> 3,1\n", 2).unwrap_err();
+        assert_eq!(error.expected, "a horizontal, vertical or 45° line");
+        assert_eq!(error.found, "'0,0 -> 3,1'");
+        assert!(solve::<Day5>("0,0 -> 0,100000", 1).is_err());
+        assert_eq!(solve::<Day5>("", 1), Ok(Answer::Int(0)));
+    }
+}
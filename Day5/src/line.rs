@@ -1,23 +1,47 @@
-use crate::point::Point;
+use aoc_common::{parse, ParseError};
+use geometry::Point2;
+
+// The grid is as big as the largest coordinate, so larger ones are refused rather than
+// allocating gigabytes. The puzzle's stay below 1000.
+const MAX_COORD: i64 = 4095;
 
 #[derive(Debug, Clone)]
 pub struct Line {
-    pub start: Point,
-    pub end: Point,
+    pub start: Point2,
+    pub end: Point2,
+}
+
+fn parse_point(section: &str) -> Result<Point2, ParseError> {
+    let (x, y) = parse::split_once(section, ",")?;
+    let x = parse::number::<i64>(x)?;
+    let y = parse::number::<i64>(y)?;
+    if x < 0 || y < 0 || x > MAX_COORD || y > MAX_COORD {
+        let expected = format!("a coordinate from 0 to {}", MAX_COORD);
+        return Err(ParseError::new(section.trim(), expected));
+    }
+    Ok(Point2::new(x, y))
 }
 
 impl Line {
-    pub fn new(start: Point, end: Point) -> Line {
+    pub fn new(start: Point2, end: Point2) -> Line {
         Line { start, end }
     }
 
-    pub fn from_string(line: &str) -> Line {
-        let sections = line.split("->").map(|s| s.trim()).collect::<Vec<&str>>();
-        if sections.len() != 2 {
-            panic!("Invalid line: {}", line);
+    pub fn from_string(line: &str) -> Result<Line, ParseError> {
+        let (start, end) = parse::split_once(line, "->")?;
+        let start = parse_point(start.trim())?;
+        let end = parse_point(end.trim())?;
+        let (dx, dy) = (end.x() - start.x(), end.y() - start.y());
+        if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+            return Err(ParseError::new(
+                line.trim(),
+                "a horizontal, vertical or 45° line",
+            ));
         }
-        let start = Point::from_string(sections[0]);
-        let end = Point::from_string(sections[1]);
-        Line::new(start, end)
+        Ok(Line::new(start, end))
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.start.x() != self.end.x() && self.start.y() != self.end.y()
     }
 }
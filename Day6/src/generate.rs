@@ -0,0 +1,9 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the number of lanternfish
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let timers: Vec<String> = (0..size.unwrap_or(300))
+        .map(|_| rng.range(1..=5).to_string())
+        .collect();
+    Generated::new(timers.join(",") + "\n")
+}
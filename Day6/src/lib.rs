@@ -0,0 +1,175 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use tracing::{debug, trace};
+
+aoc_common::config! {
+    pub struct Day6Config {
+        // Days to simulate for each part
+        part1_days: usize = 80,
+        part2_days: usize = 256,
+    }
+    check = check_config;
+}
+
+// The school grows by about 9% a day, so past this the total overflows a u64. At this limit
+// it still fits for nearly ten thousand fish, over thirty times the puzzle's.
+const MAX_DAYS: usize = 400;
+
+fn check_config(config: &Day6Config) -> Result<(), String> {
+    for (name, days) in [
+        ("part1_days", config.part1_days),
+        ("part2_days", config.part2_days),
+    ] {
+        if days > MAX_DAYS {
+            return Err(format!("{} must be at most {}", name, MAX_DAYS));
+        }
+    }
+    Ok(())
+}
+
+fn count_fish(initial_fish: &[i32], num_days: usize) -> u64 {
+    let mut num_fish_per_day = init_fish_per_day(initial_fish);
+
+    for day in 1..=num_days {
+        num_fish_per_day = increment_day(&num_fish_per_day);
+        debug!(day, fish = num_fish_per_day.iter().sum::<u64>());
+        trace!(day, by_timer = ?num_fish_per_day);
+    }
+
+    num_fish_per_day.iter().sum()
+}
+
+fn init_fish_per_day(initial_fish: &[i32]) -> Vec<u64> {
+    let mut num_fish_per_day = vec![0u64; 9];
+    for fish in initial_fish {
+        num_fish_per_day[*fish as usize] += 1;
+    }
+    num_fish_per_day
+}
+
+fn increment_day(num_fish_per_day: &[u64]) -> Vec<u64> {
+    let mut new_num_fish_per_day = vec![0u64; 9];
+    for day in 0..9 {
+        let cur_num_fish = num_fish_per_day[day];
+        if day == 0 {
+            new_num_fish_per_day[6] = cur_num_fish;
+            new_num_fish_per_day[8] = cur_num_fish;
+        } else {
+            new_num_fish_per_day[day - 1] += cur_num_fish;
+        }
+    }
+    new_num_fish_per_day
+}
+
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .split(',')
+        .map(|token| match parse::number(token)? {
+            timer if (0..=8).contains(&timer) => Ok(timer),
+            _ => Err(ParseError::new(token.trim(), "a timer from 0 to 8")),
+        })
+        .collect()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<i32>;
+    type Config = Day6Config;
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(initial_fish: &Self::Input, config: &Self::Config) -> Answer {
+        count_fish(initial_fish, config.part1_days).into()
+    }
+
+    fn part2(initial_fish: &Self::Input, config: &Self::Config) -> Answer {
+        count_fish(initial_fish, config.part2_days).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_with};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day6>(EXAMPLE, 1), Ok(Answer::Int(5934)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day6>(EXAMPLE, 2), Ok(Answer::Int(26984457539)));
+    }
+
+    #[test]
+    fn after_18_days() {
+        let config = Day6Config {
+            part1_days: 18,
+            ..Day6Config::default()
+        };
+        assert_eq!(solve_with::<Day6>(EXAMPLE, 1, &config), Ok(Answer::Int(26)));
+    }
+
+    #[test]
+    fn rejects_timers_out_of_range() {
+        let error = solve::<Day6>("3,9,1", 1).unwrap_err();
+        assert_eq!(error.expected, "a timer from 0 to 8");
+        assert_eq!(error.found, "'9'");
+        assert!(solve::<Day6>("-1", 1).is_err());
+    }
+
+    #[test]
+    fn limits_days() {
+        let config = Day6Config {
+            part2_days: 500,
+            ..Day6Config::default()
+        };
+        assert_eq!(
+            check_config(&config),
+            Err("part2_days must be at most 400".to_string())
+        );
+        assert_eq!(count_fish(&[0; 9765], MAX_DAYS), 9765 * 1888923180343411);
+    }
+
+    // Follows every fish separately instead of bucketing them by timer
+    fn naive_count(initial_fish: &[i32], num_days: usize) -> u64 {
+        let mut fish = initial_fish.to_vec();
+        for _ in 0..num_days {
+            let mut born = 0;
+            for timer in fish.iter_mut() {
+                if *timer == 0 {
+                    *timer = 6;
+                    born += 1;
+                } else {
+                    *timer -= 1;
+                }
+            }
+            fish.extend(std::iter::repeat_n(8, born));
+        }
+        fish.len() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn matches_individual_fish(
+            fish in prop::collection::vec(0i32..=8, 0..12),
+            num_days in 0usize..50,
+        ) {
+            prop_assert_eq!(count_fish(&fish, num_days), naive_count(&fish, num_days));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_common::generate::{Generated, Rng};
+
+// `size` is the number of crabs
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let positions: Vec<String> = (0..size.unwrap_or(1000))
+        .map(|_| rng.range(0..=1999).to_string())
+        .collect();
+    Generated::new(positions.join(",") + "\n")
+}
@@ -0,0 +1,119 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+type EvalOp = fn(&[i32], i32) -> u64;
+
+fn part1(crab_loc: &[i32]) -> u64 {
+    find_best_cost(crab_loc, eval_cost)
+}
+
+fn part2(crab_loc: &[i32]) -> u64 {
+    find_best_cost(crab_loc, eval_cost_triangle)
+}
+
+fn find_best_cost(crab_loc: &[i32], eval_op: EvalOp) -> u64 {
+    let (min, max) = min_max_loc(crab_loc);
+    let mut best_cost = u64::MAX;
+    for center_loc in min..=max {
+        let cost = eval_op(crab_loc, center_loc);
+        best_cost = u64::min(best_cost, cost);
+    }
+    best_cost
+}
+
+fn min_max_loc(crab_loc: &[i32]) -> (i32, i32) {
+    let mut min = i32::MAX;
+    let mut max = i32::MIN;
+    for &loc in crab_loc {
+        min = i32::min(min, loc);
+        max = i32::max(max, loc);
+    }
+    (min, max)
+}
+
+fn eval_cost(crab_loc: &[i32], center_loc: i32) -> u64 {
+    let mut cost = 0;
+    for loc in crab_loc {
+        cost += loc.abs_diff(center_loc) as u64;
+    }
+    cost
+}
+
+fn eval_cost_triangle(crab_loc: &[i32], center_loc: i32) -> u64 {
+    let mut cost = 0;
+    for loc in crab_loc {
+        let dist = loc.abs_diff(center_loc) as u64;
+        cost += (dist * (dist + 1)) / 2;
+    }
+    cost
+}
+
+// Every position from the lowest crab to the highest is tried, and the triangular costs of
+// distances this far still add up in a u64 for billions of crabs
+const MAX_POSITION: i32 = 100_000;
+
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .split(',')
+        .map(|token| match parse::number(token)? {
+            loc if (0..=MAX_POSITION).contains(&loc) => Ok(loc),
+            _ => {
+                let expected = format!("a position from 0 to {}", MAX_POSITION);
+                Err(ParseError::new(token.trim(), expected))
+            }
+        })
+        .collect()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(crab_loc: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(crab_loc).into()
+    }
+
+    fn part2(crab_loc: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(crab_loc).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day7>(EXAMPLE, 1), Ok(Answer::Int(37)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day7>(EXAMPLE, 2), Ok(Answer::Int(168)));
+    }
+
+    #[test]
+    fn costs_past_i32() {
+        assert_eq!(solve::<Day7>("0,100000", 2), Ok(Answer::Int(2500050000)));
+        let error = solve::<Day7>("16,1,2147483647", 1).unwrap_err();
+        assert_eq!(error.expected, "a position from 0 to 100000");
+        assert_eq!(error.found, "'2147483647'");
+        assert!(solve::<Day7>("-1,3", 1).is_err());
+    }
+}
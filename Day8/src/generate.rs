@@ -0,0 +1,54 @@
+use aoc_common::generate::{lines, Generated, Rng};
+
+// The segments each digit lights up when wired correctly
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+// The wires lit for a digit on a display wired by `wiring`, in random order
+fn scramble(rng: &mut Rng, wiring: &[char], digit: usize) -> String {
+    let mut segments: Vec<char> = DIGITS[digit]
+        .chars()
+        .map(|c| wiring[c as usize - 'a' as usize])
+        .collect();
+    rng.shuffle(&mut segments);
+    segments.into_iter().collect()
+}
+
+// `size` is the number of displays. Each one is wired at random, and since the output
+// digits are chosen here both answers are known.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut easy_digits = 0;
+    let mut output_sum = 0;
+
+    let displays: Vec<String> = (0..size.unwrap_or(200))
+        .map(|_| {
+            let mut wiring: Vec<char> = ('a'..='g').collect();
+            rng.shuffle(&mut wiring);
+            let mut order: Vec<usize> = (0..10).collect();
+            rng.shuffle(&mut order);
+            let patterns: Vec<String> = order
+                .into_iter()
+                .map(|digit| scramble(rng, &wiring, digit))
+                .collect();
+
+            let mut value = 0;
+            let mut output = Vec::new();
+            for _ in 0..4 {
+                let digit = rng.below(10);
+                if [1, 4, 7, 8].contains(&digit) {
+                    easy_digits += 1;
+                }
+                value = value * 10 + digit;
+                output.push(scramble(rng, &wiring, digit));
+            }
+            output_sum += value;
+
+            format!("{} | {}", patterns.join(" "), output.join(" "))
+        })
+        .collect();
+
+    Generated::new(lines(displays))
+        .with_answer(1, easy_digits)
+        .with_answer(2, output_sum)
+}
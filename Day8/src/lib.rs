@@ -0,0 +1,193 @@
+mod generate;
+
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{parallel, parse, Answer, ParseError, Solution};
+use itertools::Itertools;
+use tracing::warn;
+
+fn part1(input: &[String]) -> i64 {
+    let num_lines = input.len() / 14;
+    let mut num_easy = 0;
+    for line_index in 0..num_lines {
+        for val in &input[line_index * 14 + 10..(line_index + 1) * 14] {
+            match val.len() {
+                2 | 3 | 4 | 7 => num_easy += 1,
+                _ => (),
+            }
+        }
+    }
+
+    num_easy
+}
+
+// Parsing only lets segments 'a' to 'g' through, so every shift stays within the low 7 bits
+fn display_to_int(display: &str) -> u32 {
+    let mut bin_val = 0u32;
+    for c in display.chars() {
+        bin_val |= 1u32 << (c as u32 - 'a' as u32);
+    }
+    bin_val
+}
+
+const VALID_DIGITS: [u32; 10] = [
+    0b1110111, 0b0010010, 0b1011101, 0b1011011, 0b0111010, 0b1101011, 0b1101111, 0b1010010,
+    0b1111111, 0b1111011,
+];
+fn is_valid_permutation(line: &[u32]) -> bool {
+    line.iter().all(|&digit| VALID_DIGITS.contains(&digit))
+}
+
+fn apply_permutation(perm: &[u32], digit: u32) -> u32 {
+    let mut result = 0u32;
+    for (src_bit, &dst_bit) in perm.iter().enumerate().take(7) {
+        result |= ((digit >> src_bit) & 1) << dst_bit;
+    }
+    result
+}
+
+fn digit_to_numeral(digit: u32) -> u32 {
+    VALID_DIGITS.iter().position(|&d| d == digit).unwrap() as u32
+}
+
+// Tries every wiring until one turns all the patterns into digits, then reads the output
+fn decode_line(input_line: &[u32], output_line: &[u32]) -> Option<u32> {
+    for perm in (0..7).permutations(7) {
+        let line_perm: Vec<u32> = input_line
+            .iter()
+            .map(|digit| apply_permutation(&perm, *digit))
+            .collect();
+        if is_valid_permutation(&line_perm) {
+            let output_line: Vec<u32> = output_line
+                .iter()
+                .map(|digit| apply_permutation(&perm, *digit))
+                .collect();
+            if is_valid_permutation(&output_line) {
+                let line_result = digit_to_numeral(output_line[0]) * 1000
+                    + digit_to_numeral(output_line[1]) * 100
+                    + digit_to_numeral(output_line[2]) * 10
+                    + digit_to_numeral(output_line[3]);
+                return Some(line_result);
+            }
+        }
+    }
+
+    None
+}
+
+fn part2(input: &[String]) -> u64 {
+    let mut input_lines: Vec<Vec<u32>> = Vec::new();
+    let mut output_lines: Vec<Vec<u32>> = Vec::new();
+
+    let mut answer: u64 = 0;
+
+    let num_lines = input.len() / 14;
+    for line_index in 0..num_lines {
+        let mut input_line = Vec::new();
+        let mut output_line = Vec::new();
+        for val in &input[line_index * 14..line_index * 14 + 10] {
+            input_line.push(display_to_int(val));
+        }
+        input_lines.push(input_line);
+
+        for val in &input[line_index * 14 + 10..(line_index + 1) * 14] {
+            output_line.push(display_to_int(val));
+        }
+        output_lines.push(output_line);
+    }
+
+    // Each line is searched on its own, so they can be spread across threads
+    let line_indices: Vec<usize> = (0..input_lines.len()).collect();
+    let line_results = parallel::map(&line_indices, |&line_index| {
+        decode_line(&input_lines[line_index], &output_lines[line_index])
+    });
+
+    for (line_index, line_result) in line_results.into_iter().enumerate() {
+        match line_result {
+            Some(line_result) => answer += line_result as u64,
+            None => warn!(
+                line = line_index + 1,
+                "no wiring turns the patterns into digits"
+            ),
+        }
+    }
+
+    answer
+}
+
+fn parse_patterns(section: &str, count: usize, result: &mut Vec<String>) -> Result<(), ParseError> {
+    let mut patterns = section.split_whitespace();
+    for _ in 0..count {
+        let pattern = parse::next(&mut patterns, section, &format!("{} patterns", count))?;
+        if let Some(i) = pattern.find(|c| !('a'..='g').contains(&c)) {
+            return Err(ParseError::new(
+                parse::char_at(pattern, i),
+                "a segment from 'a' to 'g'",
+            ));
+        }
+        result.push(pattern.to_string());
+    }
+    parse::end(&mut patterns)
+}
+
+// Flattened into 14 patterns per line: the 10 unique patterns then the 4 output digits
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut result = Vec::new();
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (patterns, output) = parse::split_once(line, "|")?;
+        parse_patterns(patterns, 10, &mut result)?;
+        parse_patterns(output, 4, &mut result)?;
+    }
+    Ok(result)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<String>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day8>(EXAMPLE, 1), Ok(Answer::Int(26)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day8>(EXAMPLE, 2), Ok(Answer::Int(61229)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..5 {
+            let generated = Day8::generate(&mut Rng::new(seed), Some(20));
+            for (part, answer) in &generated.answers {
+                assert_eq!(solve::<Day8>(&generated.input, *part).as_ref(), Ok(answer));
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_common::generate::{Generated, Rng};
+
+// Splits 0..len into stretches of up to 12 as (start, end), leaving a gap of one between
+// each pair for a wall
+fn split(rng: &mut Rng, len: usize) -> Vec<(usize, usize)> {
+    let mut stretches = Vec::new();
+    let mut start = 0;
+    while start < len {
+        let end = (start + rng.range(1..=12) as usize).min(len);
+        stretches.push((start, end));
+        start = end + 1;
+    }
+    stretches
+}
+
+// `size` is the side of the square heightmap. It's cut into bands of rows by walls of 9s,
+// and each band into rectangles by walls of its own, so every rectangle is a basin. Each
+// basin has one low point, with heights rising from it in both directions up to 8.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let side = size.unwrap_or(100).max(1);
+    let mut heights = vec![vec![9u8; side]; side];
+    let mut risk = 0u64;
+    let mut basin_sizes = Vec::new();
+
+    // Everything starts as 9, and the gaps between stretches are left that way as walls
+    for (top, bottom) in split(rng, side) {
+        for (left, right) in split(rng, side) {
+            let low = (
+                left + rng.below(right - left),
+                top + rng.below(bottom - top),
+            );
+            let base = rng.range(0..=4) as usize;
+            // How steeply the basin rises across and down, so they aren't all the same shape
+            let (step_x, step_y) = (rng.range(1..=2) as usize, rng.range(1..=2) as usize);
+            for (y, row) in heights.iter_mut().enumerate().take(bottom).skip(top) {
+                for (x, height) in row.iter_mut().enumerate().take(right).skip(left) {
+                    let rise = step_x * x.abs_diff(low.0) + step_y * y.abs_diff(low.1);
+                    *height = (base + rise).min(8) as u8;
+                }
+            }
+            risk += base as u64 + 1;
+            basin_sizes.push(((right - left) * (bottom - top)) as u64);
+        }
+    }
+
+    basin_sizes.sort();
+    let largest: u64 = basin_sizes.iter().rev().take(3).product();
+    let input = heights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|height| height.to_string())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect();
+    Generated::new(input)
+        .with_answer(1, risk)
+        .with_answer(2, largest)
+}
@@ -0,0 +1,131 @@
+mod generate;
+
+//use itertools::Itertools;
+use aoc_common::animate::{self, Color, Frame};
+use aoc_common::generate::{Generated, Rng};
+use aoc_common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use search::reachable;
+
+fn get_low_points(heights: &Grid<u8>) -> Vec<Pos> {
+    heights
+        .iter()
+        .filter(|&(pos, &v)| {
+            heights
+                .neighbors4(pos)
+                .all(|adjacent| heights[adjacent] > v)
+        })
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn part1(heights: &Grid<u8>) -> i32 {
+    get_low_points(heights)
+        .iter()
+        .map(|&pos| heights[pos] as i32 + 1)
+        .sum::<i32>()
+}
+
+// Heights with each basin found so far in its own colour and the 9s between them in gray
+fn animate_basins(heights: &Grid<u8>, basin_of: &Grid<Option<usize>>, caption: String) {
+    animate::frame(|| {
+        let rows = heights
+            .rows()
+            .zip(basin_of.rows())
+            .map(|(heights, basins)| {
+                let cells = heights.iter().zip(basins).map(|(&height, basin)| {
+                    let color = match basin {
+                        Some(basin) => Some(Color::CYCLE[basin % Color::CYCLE.len()]),
+                        None if height == 9 => Some(Color::Gray),
+                        None => None,
+                    };
+                    (char::from_digit(height as u32, 10).unwrap_or('?'), color)
+                });
+                cells.collect()
+            })
+            .collect();
+        Frame::new(caption, rows)
+    });
+}
+
+fn part2(heights: &Grid<u8>) -> u64 {
+    // Only needed to draw the basins, so only kept while animating
+    let mut basin_of = animate::enabled().then(|| heights.map(|_| None));
+
+    // Each basin is everything that can be reached from its low point without crossing a 9
+    let low_points = get_low_points(heights);
+    let mut basins_sizes: Vec<u64> = low_points
+        .iter()
+        .enumerate()
+        .map(|(i, &low_point)| {
+            let basin = reachable(low_point, |&pos| {
+                heights
+                    .neighbors4(pos)
+                    .filter(|&adjacent| heights[adjacent] < 9)
+            });
+            if let Some(basin_of) = &mut basin_of {
+                for &pos in &basin {
+                    basin_of[pos] = Some(i);
+                }
+                let caption = format!("Basin {} of {}", i + 1, low_points.len());
+                animate_basins(heights, basin_of, caption);
+            }
+            basin.len() as u64
+        })
+        .collect();
+
+    basins_sizes.sort();
+    basins_sizes.iter().rev().take(3).product()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u8>;
+    type Config = ();
+    const EXAMPLES: &'static [(&'static str, &'static str)] = aoc_common::examples!("example.txt");
+
+    fn parse(input: &str, _config: &Self::Config) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(heights: &Self::Input, _config: &Self::Config) -> Answer {
+        part1(heights).into()
+    }
+
+    fn part2(heights: &Self::Input, _config: &Self::Config) -> Answer {
+        part2(heights).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve::<Day9>(EXAMPLE, 1), Ok(Answer::Int(15)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve::<Day9>(EXAMPLE, 2), Ok(Answer::Int(1134)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for seed in 0..20 {
+            let generated = Day9::generate(&mut Rng::new(seed), Some(40));
+            for (part, answer) in &generated.answers {
+                assert_eq!(solve::<Day9>(&generated.input, *part).as_ref(), Ok(answer));
+            }
+        }
+    }
+}
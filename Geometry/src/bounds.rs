@@ -0,0 +1,102 @@
+use crate::point::{Point, Vector};
+
+// An axis-aligned box. Both corners are inside it, so a box whose corners are the same point
+// holds exactly that point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> Bounds<N> {
+    pub fn new(min: Point<N>, max: Point<N>) -> Bounds<N> {
+        Bounds { min, max }
+    }
+
+    // The smallest box holding every one of `points`, or None if there aren't any
+    pub fn from_points(points: impl IntoIterator<Item = Point<N>>) -> Option<Bounds<N>> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Bounds::new(first, first), |bounds, p| {
+            Bounds::new(bounds.min.component_min(p), bounds.max.component_max(p))
+        }))
+    }
+
+    pub fn contains_point(&self, point: Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    // Whether `other` lies entirely inside this box
+    pub fn contains(&self, other: Bounds<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= other.min[i] && other.max[i] <= self.max[i])
+    }
+
+    pub fn intersects(&self, other: Bounds<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    pub fn intersection(&self, other: Bounds<N>) -> Option<Bounds<N>> {
+        if self.intersects(other) {
+            Some(Bounds::new(
+                self.min.component_max(other.min),
+                self.max.component_min(other.max),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // The smallest box holding both boxes
+    pub fn union(&self, other: Bounds<N>) -> Bounds<N> {
+        Bounds::new(
+            self.min.component_min(other.min),
+            self.max.component_max(other.max),
+        )
+    }
+
+    // Number of points along each axis
+    pub fn size(&self) -> Vector<N> {
+        Vector((self.max - self.min).0.map(|c| c + 1))
+    }
+
+    // Number of points inside
+    pub fn volume(&self) -> i64 {
+        self.size().0.iter().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bounds3, Point2, Point3};
+
+    fn cube(min: i64, max: i64) -> Bounds3 {
+        Bounds::new(Point3::new(min, min, min), Point3::new(max, max, max))
+    }
+
+    #[test]
+    fn boxes() {
+        let a = cube(10, 12);
+        let b = cube(11, 13);
+        assert_eq!(a.volume(), 27);
+        assert!(a.intersects(b));
+        assert!(!a.contains(b));
+        assert!(a.union(b).contains(b));
+        assert_eq!(a.intersection(b), Some(cube(11, 12)));
+        assert_eq!(a.intersection(cube(13, 13)), None);
+
+        let flat = Bounds::new(Point3::new(0, 11, 0), Point3::new(20, 11, 20));
+        let slice = Bounds::new(Point3::new(10, 11, 10), Point3::new(12, 11, 12));
+        assert_eq!(a.intersection(flat), Some(slice));
+    }
+
+    #[test]
+    fn bounds_of_points() {
+        let points = [Point2::new(3, -1), Point2::new(0, 4), Point2::new(2, 2)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point2::new(0, -1));
+        assert_eq!(bounds.max, Point2::new(3, 4));
+        assert!(bounds.contains_point(Point2::new(1, 0)));
+        assert_eq!(Bounds::<2>::from_points([]), None);
+    }
+}
@@ -0,0 +1,16 @@
+// Integer points, vectors and boxes in any number of dimensions, shared by the days that
+// work in 2D or 3D space
+pub mod bounds;
+pub mod point;
+pub mod rotation;
+
+pub use bounds::Bounds;
+pub use point::{Point, Vector};
+pub use rotation::Rotation;
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Vector2 = Vector<2>;
+pub type Vector3 = Vector<3>;
+pub type Bounds2 = Bounds<2>;
+pub type Bounds3 = Bounds<3>;
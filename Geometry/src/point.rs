@@ -0,0 +1,332 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+// A position. Points can be moved by vectors, and the difference of two points is the
+// vector between them.
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+// Comparing arrays directly goes through memcmp, which is twice as slow as this in the hot
+// loops of Day 19. Hash is written out alongside it so the two can't disagree.
+impl<const N: usize> PartialEq for Point<N> {
+    fn eq(&self, other: &Point<N>) -> bool {
+        (0..N).all(|i| self.0[i] == other.0[i])
+    }
+}
+
+impl<const N: usize> Hash for Point<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+// A displacement, such as an offset between two points or a direction of travel
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [i64; N]);
+
+impl<const N: usize> PartialEq for Vector<N> {
+    fn eq(&self, other: &Vector<N>) -> bool {
+        (0..N).all(|i| self.0[i] == other.0[i])
+    }
+}
+
+impl<const N: usize> Hash for Vector<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+fn zip<const N: usize>(a: [i64; N], b: [i64; N], f: impl Fn(i64, i64) -> i64) -> [i64; N] {
+    std::array::from_fn(|i| f(a[i], b[i]))
+}
+
+impl<const N: usize> Point<N> {
+    pub fn origin() -> Point<N> {
+        Point([0; N])
+    }
+
+    pub fn coords(&self) -> [i64; N] {
+        self.0
+    }
+
+    // Minimum and maximum of each coordinate, the corners of the box around both points.
+    // Not min() and max(), which Ord already provides and compare whole points.
+    pub fn component_min(&self, other: Point<N>) -> Point<N> {
+        Point(zip(self.0, other.0, i64::min))
+    }
+
+    pub fn component_max(&self, other: Point<N>) -> Point<N> {
+        Point(zip(self.0, other.0, i64::max))
+    }
+
+    pub fn manhattan_distance(&self, other: Point<N>) -> i64 {
+        (*self - other).manhattan()
+    }
+
+    pub fn chebyshev_distance(&self, other: Point<N>) -> i64 {
+        (*self - other).chebyshev()
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    pub fn zero() -> Vector<N> {
+        Vector([0; N])
+    }
+
+    pub fn coords(&self) -> [i64; N] {
+        self.0
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn chebyshev(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    // Each component reduced to -1, 0 or 1: a single step in the same general direction
+    pub fn signum(&self) -> Vector<N> {
+        Vector(self.0.map(i64::signum))
+    }
+}
+
+impl Point<2> {
+    pub fn new(x: i64, y: i64) -> Point<2> {
+        Point([x, y])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+}
+
+impl Point<3> {
+    pub fn new(x: i64, y: i64, z: i64) -> Point<3> {
+        Point([x, y, z])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> i64 {
+        self.0[2]
+    }
+}
+
+impl Vector<2> {
+    pub fn new(x: i64, y: i64) -> Vector<2> {
+        Vector([x, y])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    // Quarter turns, taking y as pointing up. On a map where y grows downwards the
+    // directions swap.
+    pub fn rotate_left(&self) -> Vector<2> {
+        Vector([-self.0[1], self.0[0]])
+    }
+
+    pub fn rotate_right(&self) -> Vector<2> {
+        Vector([self.0[1], -self.0[0]])
+    }
+}
+
+impl Vector<3> {
+    pub fn new(x: i64, y: i64, z: i64) -> Vector<3> {
+        Vector([x, y, z])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> i64 {
+        self.0[2]
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add<Vector<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, v: Vector<N>) -> Point<N> {
+        Point(zip(self.0, v.0, |a, b| a + b))
+    }
+}
+
+impl<const N: usize> Sub<Vector<N>> for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, v: Vector<N>) -> Point<N> {
+        Point(zip(self.0, v.0, |a, b| a - b))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Vector<N>;
+
+    fn sub(self, other: Point<N>) -> Vector<N> {
+        Vector(zip(self.0, other.0, |a, b| a - b))
+    }
+}
+
+impl<const N: usize> AddAssign<Vector<N>> for Point<N> {
+    fn add_assign(&mut self, v: Vector<N>) {
+        *self = *self + v;
+    }
+}
+
+impl<const N: usize> SubAssign<Vector<N>> for Point<N> {
+    fn sub_assign(&mut self, v: Vector<N>) {
+        *self = *self - v;
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
+    type Output = Vector<N>;
+
+    fn add(self, other: Vector<N>) -> Vector<N> {
+        Vector(zip(self.0, other.0, |a, b| a + b))
+    }
+}
+
+impl<const N: usize> Sub for Vector<N> {
+    type Output = Vector<N>;
+
+    fn sub(self, other: Vector<N>) -> Vector<N> {
+        Vector(zip(self.0, other.0, |a, b| a - b))
+    }
+}
+
+impl<const N: usize> AddAssign for Vector<N> {
+    fn add_assign(&mut self, other: Vector<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Vector<N> {
+    fn sub_assign(&mut self, other: Vector<N>) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        Vector(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Vector<N> {
+    type Output = Vector<N>;
+
+    fn mul(self, scale: i64) -> Vector<N> {
+        Vector(self.0.map(|c| c * scale))
+    }
+}
+
+// Written the way puzzle inputs write them, such as 1,-2,3
+fn write_coords(f: &mut fmt::Formatter, coords: &[i64]) -> fmt::Result {
+    for (i, c) in coords.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    Ok(())
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_coords(f, &self.0)
+    }
+}
+
+impl<const N: usize> fmt::Display for Vector<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_coords(f, &self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point2, Point3, Vector2, Vector3};
+
+    #[test]
+    fn points_and_vectors() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(4, 2, 3);
+        assert_eq!(b - a, Vector3::new(3, 4, 0));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a - Vector3::new(1, 1, 1), Point3::new(0, -3, 2));
+        assert_eq!(-(b - a) * 2, Vector3::new(-6, -8, 0));
+        assert_eq!(a.component_min(b), Point3::new(1, -2, 3));
+        assert_eq!(a.to_string(), "1,-2,3");
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(0, 0);
+        let b = Point2::new(3, -4);
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!((b - a).signum(), Vector2::new(1, -1));
+    }
+
+    #[test]
+    fn quarter_turns() {
+        let east = Vector2::new(1, 0);
+        assert_eq!(east.rotate_left(), Vector2::new(0, 1));
+        assert_eq!(east.rotate_right(), Vector2::new(0, -1));
+        assert_eq!(east.rotate_left().rotate_left(), -east);
+    }
+}
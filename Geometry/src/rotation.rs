@@ -0,0 +1,107 @@
+use crate::point::{Point, Vector};
+use std::ops::Mul;
+
+// One of the 24 ways of turning something in 3D space by quarter turns, such as a scanner
+// that doesn't know which way it's facing. Stored as a matrix whose entries are -1, 0 or 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub fn identity() -> Rotation {
+        Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]])
+    }
+
+    // Every orientation, starting with the identity: each of the six directions to face,
+    // spun a quarter turn at a time about the z axis
+    pub fn all() -> Vec<Rotation> {
+        const FACINGS: [[[i64; 3]; 3]; 6] = [
+            [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+            [[-1, 0, 0], [0, 1, 0], [0, 0, -1]],
+            [[0, 0, 1], [0, 1, 0], [-1, 0, 0]],
+            [[0, 0, -1], [0, 1, 0], [1, 0, 0]],
+            [[1, 0, 0], [0, 0, 1], [0, -1, 0]],
+            [[1, 0, 0], [0, 0, -1], [0, 1, 0]],
+        ];
+        let spin = Rotation([[0, -1, 0], [1, 0, 0], [0, 0, 1]]);
+
+        let mut rotations = Vec::with_capacity(24);
+        for facing in FACINGS {
+            let mut rotation = Rotation(facing);
+            for _ in 0..4 {
+                rotations.push(rotation);
+                rotation = spin * rotation;
+            }
+        }
+        rotations
+    }
+
+    fn apply(&self, v: [i64; 3]) -> [i64; 3] {
+        self.0
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    }
+}
+
+// Turns a point about the origin
+impl Mul<Point<3>> for Rotation {
+    type Output = Point<3>;
+
+    fn mul(self, p: Point<3>) -> Point<3> {
+        Point(self.apply(p.0))
+    }
+}
+
+impl Mul<Vector<3>> for Rotation {
+    type Output = Vector<3>;
+
+    fn mul(self, v: Vector<3>) -> Vector<3> {
+        Vector(self.apply(v.0))
+    }
+}
+
+// Applying `a * b` is the same as applying b and then a
+impl Mul for Rotation {
+    type Output = Rotation;
+
+    fn mul(self, other: Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (row, out) in matrix.iter_mut().enumerate() {
+            for (column, cell) in out.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.0[row][k] * other.0[k][column]).sum();
+            }
+        }
+        Rotation(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point3;
+    use std::collections::HashSet;
+
+    #[test]
+    fn twenty_four_distinct_orientations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::identity());
+
+        let p = Point3::new(1, 2, 3);
+        let turned: HashSet<Point3> = rotations.iter().map(|&r| r * p).collect();
+        assert_eq!(turned.len(), 24);
+    }
+
+    #[test]
+    fn rotations_compose() {
+        let rotations = Rotation::all();
+        let all: HashSet<Rotation> = rotations.iter().copied().collect();
+        for &a in &rotations {
+            for &b in &rotations {
+                assert!(all.contains(&(a * b)));
+            }
+        }
+
+        let p = Point3::new(1, 2, 3);
+        let (a, b) = (rotations[5], rotations[17]);
+        assert_eq!((a * b) * p, a * (b * p));
+    }
+}
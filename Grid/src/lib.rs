@@ -0,0 +1,310 @@
+// A dense, rectangular grid of cells, for the days whose input is a map
+use aoc_common::{parse, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A cell's column and row, counted from the top left
+pub type Pos = (usize, usize);
+
+// Neighborhoods are lists of (dx, dy) offsets from a cell, with y growing downwards
+pub const ORTHOGONAL: &[(isize, isize)] = &[(0, -1), (-1, 0), (0, 1), (1, 0)];
+pub const DIAGONAL: &[(isize, isize)] = &[(-1, -1), (1, -1), (-1, 1), (1, 1)];
+pub const ALL: &[(isize, isize)] = &[
+    (0, -1),
+    (-1, 0),
+    (0, 1),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // Row by row
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // `cells` is read row by row, and must fill a whole number of rows
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    // Builds a grid from a map with one character per cell. `cell` returns None for
+    // characters that don't belong in the map, which are reported as not being `expected`.
+    pub fn parse_chars(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in input.lines().map(|line| line.trim_end()) {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(ParseError::new(parse::char_at(line, i), expected)),
+                }
+            }
+
+            let row_width = cells.len() - row_start;
+            let width = *width.get_or_insert(row_width);
+            if width != row_width {
+                return Err(ParseError::new(line, format!("a row of {} cells", width)));
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_vec(width, cells)),
+            _ => Err(ParseError::new(input, "a map")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    // Position of the cell stored at `index` in row-by-row order, and back again
+    pub fn pos(&self, index: usize) -> Pos {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn index(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[self.index(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    // Returns false, leaving the grid alone, when `pos` is outside it
+    pub fn set(&mut self, pos: Pos, value: T) -> bool {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Every cell in row-by-row order
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // The positions reached from `pos` by each of `offsets` that land inside the grid
+    pub fn neighbors<'a>(
+        &self,
+        (x, y): Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            if x < width && y < height {
+                Some((x, y))
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbors(pos, ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbors(pos, ALL)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Draws the grid one character per cell
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Grid<u8> {
+    // A map of single decimal digits, such as a height or risk map
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_chars(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[Grid::index(self, pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        let index = Grid::index(self, pos);
+        &mut self.cells[index]
+    }
+}
+
+// Each cell is printed with its own Display, one row per line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "2199\n3987\n9856\n";
+
+    #[test]
+    fn parses_digits() {
+        let grid = Grid::parse_digits(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid[(1, 0)], 1);
+        assert_eq!(grid.get((3, 2)), Some(&6));
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.to_string(), MAP.trim_end());
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let input = "12\n3x\n";
+        let error = Grid::parse_digits(input).unwrap_err().locate(input);
+        assert_eq!(error.found, "'x'");
+        assert_eq!(error.location.unwrap().column, 2);
+
+        let error = Grid::parse_digits("12\n345\n").unwrap_err();
+        assert_eq!(error.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(grid.neighbors((1, 1), &[(2, 0), (0, -1)]).count(), 1);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = Grid::from_vec(3, (0..6).collect());
+        assert!(grid.set((2, 1), 9));
+        assert!(!grid.set((3, 1), 9));
+        assert_eq!(grid.row(1), &[3, 4, 9]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 9]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.render(|&v| if v > 3 { '#' } else { '.' }), "...\n.##");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{parse_run_args, print_records, run_day, RunArgs};
+use aoc_common::animate::{self, Frame};
+use aoc_common::Format;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_FPS: u32 = 10;
+
+#[derive(Debug)]
+pub struct AnimateArgs {
+    run: RunArgs,
+    fps: u32,
+    // Write each frame to a numbered text file here instead of playing them
+    frames: Option<PathBuf>,
+}
+
+// Takes the animate-only options out and leaves the rest to the run argument parser
+pub fn parse_animate_args(args: &[String]) -> Result<AnimateArgs, String> {
+    let mut fps = DEFAULT_FPS;
+    let mut frames = None;
+    let mut run_args = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+        match arg.as_str() {
+            "--fps" => {
+                let rate = value("--fps")?;
+                fps = match rate.parse::<u32>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid frame rate '{}'", rate)),
+                };
+            }
+            "--frames" => frames = Some(PathBuf::from(value("--frames")?)),
+            _ => run_args.push(arg.clone()),
+        }
+    }
+
+    let run = parse_run_args(&run_args)?;
+    if run.days.len() > 1 {
+        return Err("Days are animated one at a time".to_string());
+    }
+    if run.format != Format::Text {
+        return Err("--format is not supported by animate".to_string());
+    }
+    if run.export.is_some() {
+        return Err("--export is not supported by animate".to_string());
+    }
+
+    Ok(AnimateArgs { run, fps, frames })
+}
+
+// The terminal size from the environment, as there's no portable way to ask the terminal
+fn viewport() -> (usize, usize) {
+    let size = |name: &str, default: usize| {
+        env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    };
+    (size("COLUMNS", 120), size("LINES", 40))
+}
+
+fn play(fps: u32) -> Box<dyn FnMut(Frame) + Send> {
+    let (width, height) = viewport();
+    let delay = Duration::from_secs(1) / fps;
+    // Clear the screen and hide the cursor, then redraw each frame over the last
+    print!("\x1b[2J\x1b[?25l");
+    Box::new(move |frame| {
+        let mut stdout = io::stdout().lock();
+        let _ = write!(stdout, "\x1b[H{}\x1b[J", frame.ansi(width, height));
+        let _ = stdout.flush();
+        thread::sleep(delay);
+    })
+}
+
+fn dump(dir: PathBuf) -> Result<Box<dyn FnMut(Frame) + Send>, String> {
+    fs::create_dir_all(&dir).map_err(|e| format!("Unable to create {}: {}", dir.display(), e))?;
+    let mut count = 0;
+    Ok(Box::new(move |frame| {
+        let path = dir.join(format!("frame{:05}.txt", count));
+        if let Err(e) = fs::write(&path, frame.plain()) {
+            eprintln!("Unable to write {}: {}", path.display(), e);
+        }
+        count += 1;
+    }))
+}
+
+pub fn animate(args: &AnimateArgs) -> Result<(), String> {
+    args.run.verbosity.init();
+    let playing = args.frames.is_none();
+    let player = match &args.frames {
+        Some(dir) => dump(dir.clone())?,
+        None => play(args.fps),
+    };
+
+    animate::set_player(Some(player));
+    let result = run_day(args.run.days[0], &args.run);
+    animate::set_player(None);
+    if playing {
+        print!("\x1b[?25h");
+    }
+
+    print_records(&result?, &args.run);
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use crate::{parse_run_args, read_input, RunArgs};
+use aoc::workspace_dir;
+use aoc_common::bench::{compare, Baseline, Change, Stats};
+use aoc_common::{input_hash, parallel, Format};
+use std::path::PathBuf;
+
+const DEFAULT_SAMPLES: usize = 20;
+
+#[derive(Debug)]
+pub struct BenchArgs {
+    run: RunArgs,
+    samples: usize,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+}
+
+// Baseline names become file names, so they are kept to something that can't escape the
+// baseline directory
+fn check_baseline_name(name: String) -> Result<String, String> {
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid && !name.is_empty() {
+        Ok(name)
+    } else {
+        Err(format!(
+            "Invalid baseline name '{}', expected letters, digits, - or _",
+            name
+        ))
+    }
+}
+
+// Baselines are kept with the build output, like criterion's
+fn baseline_path(name: &str) -> PathBuf {
+    workspace_dir()
+        .join("target")
+        .join("aoc-bench")
+        .join(format!("{}.toml", name))
+}
+
+// Takes the bench-only options out and leaves the rest to the run argument parser
+pub fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut samples = DEFAULT_SAMPLES;
+    let mut save_baseline = None;
+    let mut baseline = None;
+    let mut run_args = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+        match arg.as_str() {
+            "--samples" | "-n" => {
+                let count = value("--samples")?;
+                samples = match count.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid sample count '{}'", count)),
+                };
+            }
+            "--save-baseline" => {
+                save_baseline = Some(check_baseline_name(value("--save-baseline")?)?)
+            }
+            "--baseline" => baseline = Some(check_baseline_name(value("--baseline")?)?),
+            _ => run_args.push(arg.clone()),
+        }
+    }
+
+    let run = parse_run_args(&run_args)?;
+    if run.format != Format::Text {
+        return Err("--format is not supported by bench".to_string());
+    }
+    if run.export.is_some() {
+        return Err("--export is not supported by bench".to_string());
+    }
+
+    Ok(BenchArgs {
+        run,
+        samples,
+        save_baseline,
+        baseline,
+    })
+}
+
+// Timings taken on a different input can't be compared, so those get no verdict
+fn comparison(
+    baseline: &Option<Baseline>,
+    day: usize,
+    phase: &str,
+    stats: &Stats,
+    same_input: bool,
+) -> String {
+    let old = match baseline.as_ref().and_then(|b| b.get(day, phase)) {
+        Some(old) => old,
+        None => return String::new(),
+    };
+    if !same_input {
+        return "  n/a (different input)".to_string();
+    }
+
+    let (ratio, change) = compare(&old, stats);
+    let verdict = match change {
+        Change::Improved => "improved",
+        Change::Regressed => "regressed",
+        Change::NoChange => "no change",
+    };
+    format!("  {:+.1}% ({})", ratio * 100.0, verdict)
+}
+
+pub fn bench(args: &BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(name) => Some(Baseline::load(&baseline_path(name))?),
+        None => None,
+    };
+    // Saving adds to what's already in the baseline, so days benchmarked separately can share
+    // one
+    let mut saved = match &args.save_baseline {
+        Some(name) if baseline_path(name).exists() => Baseline::load(&baseline_path(name))?,
+        _ => Baseline::default(),
+    };
+
+    // Days are always benchmarked one at a time, --parallel only lets them use threads inside
+    parallel::set_enabled(args.run.parallel);
+    args.run.verbosity.init();
+
+    for &day in &args.run.days {
+        let (path, input) = read_input(day, &args.run)?;
+        let hash = input_hash(&input);
+        let compared = baseline.as_ref().and_then(|b| b.input_hash(day));
+        let same_input = compared.is_none_or(|old| old == hash);
+        if !same_input {
+            println!("Day {:>2} baseline was measured on a different input", day);
+        }
+        saved.set_input_hash(day, &hash);
+        let report = aoc::DAYS[day - 1]
+            .bench(&input, &args.run.parts, &args.run.settings, args.samples)
+            .map_err(|e| e.with_file(&path).to_string())?;
+
+        for (phase, stats) in &report.phases {
+            println!(
+                "Day {:>2} {:<5}  mean {:>12?}  median {:>12?}  stddev {:>12?}  ({} x {}){}",
+                day,
+                phase,
+                stats.mean,
+                stats.median,
+                stats.stddev,
+                stats.samples,
+                stats.iterations,
+                comparison(&baseline, day, phase, stats, same_input)
+            );
+            saved.record(day, phase, stats);
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = baseline_path(name);
+        saved
+            .save(&path)
+            .map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+        println!("Saved baseline '{}'", name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use aoc::{default_input_path, workspace_dir, DAYS};
+use aoc_common::input::{self, Source};
+use aoc_common::{input_hash, Answer, AnswerRegistry, InputStore, Settings, Verdict};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: verify [<day|all>] [--answers <path>] [--user <name>] [--record]";
+
+#[derive(Debug)]
+struct VerifyArgs {
+    days: Vec<usize>,
+    answers: PathBuf,
+    // Whose stored inputs to check, rather than whatever each day would read by default
+    user: Option<String>,
+    // Store answers for inputs that have none yet
+    record: bool,
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+    recorded: usize,
+    errors: usize,
+    // Parse and solve time across every day
+    time: Duration,
+}
+
+fn parse_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut days: Vec<usize> = (1..=DAYS.len()).collect();
+    let mut answers = workspace_dir().join("answers.toml");
+    let mut user = None;
+    let mut record = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" | "-a" => match args.next() {
+                Some(path) => answers = PathBuf::from(path),
+                None => return Err("Missing value for --answers".to_string()),
+            },
+            "--user" | "-u" => match args.next() {
+                Some(name) => {
+                    InputStore::check_user(name)?;
+                    user = Some(name.clone());
+                }
+                None => return Err("Missing value for --user".to_string()),
+            },
+            "--record" => record = true,
+            "all" => days = (1..=DAYS.len()).collect(),
+            other => match other.parse::<usize>() {
+                Ok(day) if day >= 1 && day <= DAYS.len() => days = vec![day],
+                _ => return Err(format!("Unknown argument '{}'\n{}", other, USAGE)),
+            },
+        }
+    }
+
+    Ok(VerifyArgs {
+        days,
+        answers,
+        user,
+        record,
+    })
+}
+
+// Grids don't fit on a status line, so they are only printed in full when they need a look
+fn short_answer(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        "[grid]".to_string()
+    } else {
+        answer.to_string()
+    }
+}
+
+fn verify_day(day: usize, args: &VerifyArgs, registry: &mut AnswerRegistry, totals: &mut Totals) {
+    let source = Source::default_for(args.user.as_deref(), day, default_input_path(day));
+    if !source.exists() {
+        println!("Day {:>2}: SKIP (no input)", day);
+        return;
+    }
+    let (path, input) = match input::read(&source, &[]) {
+        Ok(read) => read,
+        Err(message) => {
+            println!("Day {:>2}: ERROR", day);
+            eprintln!("{}", message);
+            totals.errors += 1;
+            return;
+        }
+    };
+    let hash = input_hash(&input);
+
+    // Known answers are for the puzzle as published, so every day runs with its defaults
+    let report = match DAYS[day - 1].run(&input, &[1, 2], &Settings::default()) {
+        Ok(report) => report,
+        Err(e) => {
+            println!("Day {:>2}: ERROR", day);
+            eprintln!("{}", e.with_file(&path));
+            totals.errors += 1;
+            return;
+        }
+    };
+
+    totals.time += report.parse_time;
+    for result in &report.parts {
+        totals.time += result.time;
+        let answer = &result.answer;
+        let verdict = registry.check(day, &hash, result.part, answer);
+        let status = match &verdict {
+            Verdict::Pass => {
+                totals.passed += 1;
+                "PASS".to_string()
+            }
+            Verdict::Fail { expected } => {
+                totals.failed += 1;
+                format!("FAIL (expected {})", expected.replace('\n', "\\n"))
+            }
+            Verdict::Unknown if args.record => {
+                registry.record(day, &hash, result.part, answer);
+                totals.recorded += 1;
+                "RECORDED".to_string()
+            }
+            Verdict::Unknown => {
+                totals.unknown += 1;
+                "UNKNOWN".to_string()
+            }
+        };
+
+        println!(
+            "Day {:>2} Part {}: {:<8} {} ({:?})",
+            day,
+            result.part,
+            status,
+            short_answer(answer),
+            result.time
+        );
+        if answer.is_multiline() && verdict != Verdict::Pass {
+            println!("{}", answer);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    };
+
+    let mut registry = match AnswerRegistry::load(&args.answers) {
+        Ok(registry) => registry,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    };
+
+    let mut totals = Totals::default();
+    for &day in &args.days {
+        verify_day(day, &args, &mut registry, &mut totals);
+    }
+
+    if totals.recorded > 0 {
+        if let Err(e) = registry.save(&args.answers) {
+            eprintln!("Unable to write {}: {}", args.answers.display(), e);
+            process::exit(1);
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} unknown, {} recorded, {} errors ({:?})",
+        totals.passed, totals.failed, totals.unknown, totals.recorded, totals.errors, totals.time
+    );
+
+    if totals.failed > 0 || totals.errors > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,42 @@
+// The table of days and input locations, shared by the aoc runner and the verify binary
+use aoc_common::DynSolution;
+use std::path::PathBuf;
+
+pub const DAYS: [&dyn DynSolution; 25] = [
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+pub fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// Inputs live next to each day's crate unless a path is given explicitly
+pub fn default_input_path(day: usize) -> PathBuf {
+    workspace_dir()
+        .join(format!("Day{}", day))
+        .join("input.txt")
+}
@@ -0,0 +1,415 @@
+mod animate;
+mod bench;
+
+use aoc::{default_input_path, DAYS};
+use aoc_common::animate::set_player;
+use aoc_common::export::{ImageFormat, Recorder};
+use aoc_common::input::{self, Source};
+use aoc_common::output::{self, Record, CSV_HEADER};
+use aoc_common::parallel;
+use aoc_common::{input_hash, Answer, Format, InputStore, Settings, Verbosity};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--example <n>] [--user <name>]
+                      [--format <text|json|csv>] [--set <key=value>]... [--parallel]
+                      [-q|-v|-vv] [--export <path.ppm|png|gif>]
+    aoc bench <day|all> [--part <1|2>] [--input <path|->] [--example <n>] [--user <name>]
+                        [--set <key=value>]... [--parallel] [-q|-v|-vv] [--samples <n>]
+                        [--save-baseline <name>] [--baseline <name>]
+    aoc import <day> <path|-> [--user <name>]
+    aoc settings <day|all>
+    aoc generate <day> [--seed <n>] [--size <n>] [--output <path>] [--check]
+    aoc animate <day> [--part <1|2>] [--input <path|->] [--example <n>] [--user <name>]
+                      [--set <key=value>]... [-q|-v|-vv] [--fps <n>] [--frames <dir>]";
+
+#[derive(Debug)]
+struct RunArgs {
+    days: Vec<usize>,
+    parts: Vec<u32>,
+    // Where to read the input from instead of the input store or the day's input.txt
+    source: Option<Source>,
+    // Whose inputs to read from the store
+    user: Option<String>,
+    format: Format,
+    // Changes to the day's puzzle constants
+    settings: Settings,
+    // Let days split their work across threads, and run them alongside each other
+    parallel: bool,
+    verbosity: Verbosity,
+    // Where to save what the day draws as an image
+    export: Option<PathBuf>,
+}
+
+fn parse_day(arg: &str) -> Result<Vec<usize>, String> {
+    if arg == "all" {
+        return Ok((1..=DAYS.len()).collect());
+    }
+
+    match arg.parse::<usize>() {
+        Ok(day) if day >= 1 && day <= DAYS.len() => Ok(vec![day]),
+        _ => Err(format!(
+            "Invalid day '{}', expected 1-{} or 'all'",
+            arg,
+            DAYS.len()
+        )),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter();
+    let days = match args.next() {
+        Some(arg) => parse_day(arg)?,
+        None => return Err("Missing day".to_string()),
+    };
+
+    let mut parts = vec![1, 2];
+    let mut source = None;
+    let mut user = None;
+    let mut format = Format::Text;
+    let mut settings = Settings::default();
+    let mut parallel = false;
+    let mut verbosity = Verbosity::default();
+    let mut export = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                parts = match args.next().map(|s| s.as_str()) {
+                    Some("1") => vec![1],
+                    Some("2") => vec![2],
+                    Some(other) => return Err(format!("Invalid part '{}'", other)),
+                    None => return Err("Missing value for --part".to_string()),
+                };
+            }
+            "--input" | "-i" => match args.next() {
+                Some(path) => source = Some(Source::from_input_arg(path)),
+                None => return Err("Missing value for --input".to_string()),
+            },
+            "--example" | "-e" => match args.next() {
+                Some(n) => source = Some(Source::from_example_arg(n)?),
+                None => return Err("Missing value for --example".to_string()),
+            },
+            "--user" | "-u" => match args.next() {
+                Some(name) => {
+                    InputStore::check_user(name)?;
+                    user = Some(name.clone());
+                }
+                None => return Err("Missing value for --user".to_string()),
+            },
+            "--format" | "-f" => match args.next() {
+                Some(value) => format = value.parse()?,
+                None => return Err("Missing value for --format".to_string()),
+            },
+            "--parallel" => parallel = true,
+            "--set" | "-s" => match args.next() {
+                Some(setting) => settings.push_arg(setting)?,
+                None => return Err("Missing value for --set".to_string()),
+            },
+            "--export" => match args.next() {
+                Some(path) => {
+                    ImageFormat::from_path(Path::new(path))?;
+                    export = Some(PathBuf::from(path));
+                }
+                None => return Err("Missing value for --export".to_string()),
+            },
+            flag if verbosity.apply_flag(flag) => {}
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    // Each day has its own settings, so they only make sense for one day at a time
+    if !settings.is_empty() && days.len() > 1 {
+        return Err("--set can only be used with a single day".to_string());
+    }
+    for &day in &days {
+        DAYS[day - 1].check_settings(&settings)?;
+    }
+    if source.is_some() && days.len() > 1 {
+        return Err("--input and --example can only be used with a single day".to_string());
+    }
+    if export.is_some() && days.len() > 1 {
+        return Err("--export can only be used with a single day".to_string());
+    }
+    if source.is_some() && user.is_some() {
+        return Err("--user can't be used with --input or --example".to_string());
+    }
+
+    Ok(RunArgs {
+        days,
+        parts,
+        source,
+        user,
+        format,
+        settings,
+        parallel,
+        verbosity,
+        export,
+    })
+}
+
+// Grids are printed below the label so their rows stay aligned
+fn print_answer(day: usize, part: u32, answer: &Answer, time: Duration) {
+    if answer.is_multiline() {
+        println!("Day {} Part {} ({:?}):\n{}", day, part, time, answer);
+    } else {
+        println!("Day {} Part {}: {} ({:?})", day, part, answer, time);
+    }
+}
+
+fn read_input(day: usize, args: &RunArgs) -> Result<(PathBuf, String), String> {
+    let source = match &args.source {
+        Some(source) => source.clone(),
+        None => Source::default_for(args.user.as_deref(), day, default_input_path(day)),
+    };
+    input::read(&source, DAYS[day - 1].examples())
+}
+
+fn run_day(day: usize, args: &RunArgs) -> Result<Vec<Record>, String> {
+    let read_time_start = Instant::now();
+    let (path, input) = read_input(day, args)?;
+    let read_time = read_time_start.elapsed();
+
+    let report = DAYS[day - 1]
+        .run(&input, &args.parts, &args.settings)
+        .map_err(|e| e.with_file(&path).to_string())?;
+    Ok(output::records(
+        day,
+        &input_hash(&input),
+        read_time,
+        &report,
+    ))
+}
+
+fn print_records(records: &[Record], args: &RunArgs) {
+    for record in records {
+        match args.format {
+            Format::Text if args.verbosity.is_quiet() => println!("{}", record.answer),
+            Format::Text => {
+                print_answer(record.day, record.part, &record.answer, record.solve_time)
+            }
+            Format::Json => println!("{}", record.to_json()),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    if args.format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+    if let Some(path) = &args.export {
+        return export(path, args);
+    }
+
+    // In parallel every day has to finish before anything is printed, so the output stays in
+    // day order. Times are then measured while the days compete for the CPU.
+    if parallel::enabled() {
+        for records in parallel::map(&args.days, |&day| run_day(day, args)) {
+            print_records(&records?, args);
+        }
+    } else {
+        for &day in &args.days {
+            print_records(&run_day(day, args)?, args);
+        }
+    }
+
+    Ok(())
+}
+
+// Runs the one day with everything it draws recorded, then saves that as an image
+fn export(path: &Path, args: &RunArgs) -> Result<(), String> {
+    let format = ImageFormat::from_path(path)?;
+    let recorder = Arc::new(Mutex::new(Recorder::new(format)));
+    let recording = Arc::clone(&recorder);
+    set_player(Some(Box::new(move |frame| {
+        recording.lock().unwrap().record(&frame)
+    })));
+    let records = run_day(args.days[0], args);
+    set_player(None);
+    print_records(&records?, args);
+
+    let recorder = recorder.lock().unwrap();
+    recorder
+        .save(path)
+        .map_err(|e| format!("Day {}: {}", args.days[0], e))?;
+    match recorder.frames() {
+        1 => eprintln!("Exported to {}", path.display()),
+        frames => eprintln!("Exported {} frames to {}", frames, path.display()),
+    }
+    Ok(())
+}
+
+// aoc import <day> <path|-> [--user <name>]
+fn import(args: &[String]) -> Result<(), String> {
+    let mut user = InputStore::default_user();
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--user" | "-u" => match args.next() {
+                Some(name) => user = name.clone(),
+                None => return Err("Missing value for --user".to_string()),
+            },
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, path) = match positional[..] {
+        [day, path] => (day, path),
+        _ => return Err(USAGE.to_string()),
+    };
+    let day = match parse_day(day)?[..] {
+        [day] => day,
+        _ => return Err("Inputs are imported one day at a time".to_string()),
+    };
+
+    let (_, input) = input::read(&Source::from_input_arg(path), &[])?;
+    let (stored, hash) = InputStore::open()?.import(&user, day, &input)?;
+    println!(
+        "Imported day {} for '{}' to {}",
+        day,
+        user,
+        stored.display()
+    );
+    println!("sha256 {}", hash);
+    Ok(())
+}
+
+// aoc settings <day|all>
+fn settings(args: &[String]) -> Result<(), String> {
+    let days = match args {
+        [day] => parse_day(day)?,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    for day in days {
+        let settings = DAYS[day - 1].default_settings();
+        if settings.is_empty() {
+            println!("Day {}: no settings", day);
+            continue;
+        }
+        println!("Day {}:", day);
+        for (name, value) in settings {
+            println!("    {} = {}", name, value);
+        }
+    }
+    Ok(())
+}
+
+// aoc generate <day> [--seed <n>] [--size <n>] [--output <path>] [--check]
+fn generate(args: &[String]) -> Result<(), String> {
+    let mut seed = None;
+    let mut size = None;
+    let mut output = None;
+    let mut check = false;
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+        match arg.as_str() {
+            "--seed" => {
+                let text = value("--seed")?;
+                let parsed = text.parse::<u64>();
+                seed = Some(parsed.map_err(|_| format!("Invalid seed '{}'", text))?);
+            }
+            "--size" => {
+                let text = value("--size")?;
+                size = match text.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid size '{}'", text)),
+                };
+            }
+            "--output" | "-o" => output = Some(PathBuf::from(value("--output")?)),
+            "--check" => check = true,
+            _ => positional.push(arg),
+        }
+    }
+
+    let day = match positional[..] {
+        [day] => match parse_day(day)?[..] {
+            [day] => day,
+            _ => return Err("Inputs are generated one day at a time".to_string()),
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+
+    // Without a seed one is picked from the clock, and reported so the input can be made again
+    let seed = seed.unwrap_or_else(|| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        now.as_nanos() as u64
+    });
+    eprintln!("Day {} seed {}", day, seed);
+
+    let generated = DAYS[day - 1].generate(seed, size);
+    match &output {
+        Some(path) => fs::write(path, &generated.input)
+            .map_err(|e| format!("Unable to write {}: {}", path.display(), e))?,
+        None => print!("{}", generated.input),
+    }
+    for (part, answer) in &generated.answers {
+        eprintln!("Part {} should be {}", part, answer);
+    }
+    if !check {
+        return Ok(());
+    }
+
+    let report = DAYS[day - 1]
+        .run(&generated.input, &[1, 2], &Settings::default())
+        .map_err(|e| format!("Generated input doesn't parse: {}", e))?;
+    let mut mismatches = 0;
+    for result in &report.parts {
+        match generated.answer(result.part) {
+            Some(expected) if *expected != result.answer => {
+                eprintln!(
+                    "Part {}: MISMATCH, expected {} but solved {}",
+                    result.part, expected, result.answer
+                );
+                mismatches += 1;
+            }
+            Some(_) => eprintln!("Part {}: ok", result.part),
+            None => eprintln!("Part {}: {} (no known answer)", result.part, result.answer),
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(format!("Day {} seed {} solved incorrectly", day, seed)),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => parse_run_args(&args[1..]).and_then(|run_args| {
+            parallel::set_enabled(run_args.parallel);
+            run_args.verbosity.init();
+            run(&run_args)
+        }),
+        Some("bench") => {
+            bench::parse_bench_args(&args[1..]).and_then(|bench_args| bench::bench(&bench_args))
+        }
+        Some("animate") => animate::parse_animate_args(&args[1..])
+            .and_then(|animate_args| animate::animate(&animate_args)),
+        Some("import") => import(&args[1..]),
+        Some("settings") => settings(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::path_to;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// The shortest path, in number of steps, from `start` to the first state that `is_goal`
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::new();
+    parents.insert(start.clone(), None);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(path_to(&parents, state));
+        }
+
+        for next in successors(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+// Every state that can be reached from `start`, including `start` itself, nearest first
+pub fn reachable<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen: HashSet<S> = HashSet::new();
+    seen.insert(start.clone());
+    let mut reached = vec![start];
+    let mut next_index = 0;
+    while next_index < reached.len() {
+        for next in successors(&reached[next_index]) {
+            if seen.insert(next.clone()) {
+                reached.push(next);
+            }
+        }
+        next_index += 1;
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4, with a shortcut from 0 to 3
+    fn successors(&n: &u32) -> Vec<u32> {
+        match n {
+            0 => vec![1, 3],
+            4 => vec![],
+            _ => vec![n + 1],
+        }
+    }
+
+    #[test]
+    fn finds_shortest_path() {
+        assert_eq!(bfs(0, successors, |&n| n == 4), Some(vec![0, 3, 4]));
+        assert_eq!(bfs(0, successors, |&n| n == 0), Some(vec![0]));
+        assert_eq!(bfs(1, successors, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn floods_from_start() {
+        assert_eq!(reachable(2, successors), vec![2, 3, 4]);
+        assert_eq!(reachable(0, successors).len(), 5);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::path_to;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// A path from `start` to a state that `is_goal`, not necessarily the shortest. Successors
+// are explored in reverse order of listing, so the first one listed is tried last.
+pub fn dfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::new();
+    parents.insert(start.clone(), None);
+    let mut stack = vec![start];
+    while let Some(state) = stack.pop() {
+        if is_goal(&state) {
+            return Some(path_to(&parents, state));
+        }
+
+        for next in successors(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                stack.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+// Counts the distinct ways of getting from `start` to a goal. States are not remembered, so
+// they must carry enough history (such as the places already visited) for `successors` to
+// avoid going round in circles. Goals are counted but not explored further.
+pub fn count_paths<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> u64
+where
+    I: IntoIterator<Item = S>,
+{
+    let mut num_paths = 0;
+    let mut stack = vec![start];
+    while let Some(state) = stack.pop() {
+        if is_goal(&state) {
+            num_paths += 1;
+            continue;
+        }
+        stack.extend(successors(&state));
+    }
+
+    num_paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_path() {
+        // A ring of 6 that can be walked either way
+        let successors = |&n: &u32| [(n + 1) % 6, (n + 5) % 6];
+        let path = dfs(0, successors, |&n| n == 3).unwrap();
+        assert_eq!((path[0], path[path.len() - 1]), (0, 3));
+        assert!(path.windows(2).all(|w| successors(&w[0]).contains(&w[1])));
+        assert_eq!(dfs(0, successors, |&n| n == 7), None);
+    }
+
+    #[test]
+    fn counts_paths_through_a_grid() {
+        // Monotone paths across a 3x3 lattice of points
+        let successors = |&(x, y): &(u32, u32)| {
+            let mut next = Vec::new();
+            if x < 2 {
+                next.push((x + 1, y));
+            }
+            if y < 2 {
+                next.push((x, y + 1));
+            }
+            next
+        };
+        assert_eq!(count_paths((0, 0), successors, |&p| p == (2, 2)), 6);
+    }
+}
@@ -0,0 +1,154 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+// A state waiting in the open set, ordered by its estimated total cost
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Reversed so that BinaryHeap pops the cheapest first. Ties go to the state that has come
+// further, which is closer to the goal.
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (Reverse(&self.estimate), &self.cost).cmp(&(Reverse(&other.estimate), &other.cost))
+    }
+}
+
+// The cheapest path from `start` to a state that `is_goal`, along with its cost.
+// `successors` lists each next state with the cost of the step to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+// Dijkstra guided by `heuristic`, an estimate of the remaining cost from a state to the
+// nearest goal. The path found is the cheapest as long as the heuristic never overestimates.
+// Costs start from C::default(), which must be zero.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state seen so far with the cheapest known cost to it and the state that cost
+    // came through. States are referred to by their index in here.
+    let mut states: Vec<(S, Option<usize>, C)> = Vec::new();
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut open = BinaryHeap::new();
+
+    let zero = C::default();
+    indices.insert(start.clone(), 0);
+    open.push(Entry {
+        estimate: heuristic(&start),
+        cost: zero,
+        index: 0,
+    });
+    states.push((start, None, zero));
+
+    while let Some(Entry { cost, index, .. }) = open.pop() {
+        if cost > states[index].2 {
+            continue;
+        }
+
+        // Cloned so that `states` can be added to while going through the successors
+        let state = states[index].0.clone();
+        if is_goal(&state) {
+            let mut path = vec![state];
+            let mut parent = states[index].1;
+            while let Some(i) = parent {
+                path.push(states[i].0.clone());
+                parent = states[i].1;
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.get(&next) {
+                Some(&i) if states[i].2 <= next_cost => continue,
+                Some(&i) => {
+                    states[i].1 = Some(index);
+                    states[i].2 = next_cost;
+                    i
+                }
+                None => {
+                    let i = states.len();
+                    indices.insert(next.clone(), i);
+                    states.push((next.clone(), Some(index), next_cost));
+                    i
+                }
+            };
+            open.push(Entry {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1-> b -1-> c -1-> d, plus an expensive direct a -5-> d and a cheap detour b -1-> d
+    fn successors(&node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1), ('d', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        let (path, cost) = dijkstra('a', successors, |&n| n == 'd').unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(path, vec!['a', 'b', 'd']);
+        assert_eq!(dijkstra('c', successors, |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn heuristic_gives_the_same_cost() {
+        // Walking a line of 10 from 0 to 9 with a distance heuristic
+        let successors = |&n: &i32| vec![(n - 1, 1), (n + 1, 1)];
+        let (path, cost) = astar(0, successors, |&n| (9 - n).abs(), |&n| n == 9).unwrap();
+        assert_eq!(cost, 9);
+        assert_eq!(path, (0..=9).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,23 @@
+// Graph searches over any state type. Graphs are never built up front: each search is given
+// a start state and a function listing the states reachable in one step from a given state.
+pub mod bfs;
+pub mod dfs;
+pub mod dijkstra;
+
+pub use bfs::{bfs, reachable};
+pub use dfs::{count_paths, dfs};
+pub use dijkstra::{astar, dijkstra};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Walks back from `goal` through the state each one was first reached from, giving the
+// path from the start (which has no parent) to the goal
+fn path_to<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
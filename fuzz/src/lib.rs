@@ -0,0 +1,19 @@
+// Shared bodies for the fuzz targets, one target per day
+use aoc_common::Solution;
+
+// Parsing has to turn any input into either a value or a ParseError, never a panic
+pub fn parse<S: Solution>(data: &[u8]) -> Option<S::Input> {
+    let input = std::str::from_utf8(data).ok()?;
+    S::parse(input, &S::Config::default()).ok()
+}
+
+// Parses and then runs both parts, so the parts are fuzzed too. Days 4, 5, 7, 8, 10, 16, 18
+// and 22 use this, because their parts decode the input, size their state by it or do
+// arithmetic on its numbers, and could panic on input that parses.
+pub fn solve<S: Solution>(data: &[u8]) {
+    if let Some(parsed) = parse::<S>(data) {
+        let config = S::Config::default();
+        S::part1(&parsed, &config);
+        S::part2(&parsed, &config);
+    }
+}